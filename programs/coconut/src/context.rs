@@ -2,143 +2,1825 @@
 
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    associated_token::AssociatedToken,
+    associated_token::{get_associated_token_address_with_program_id, AssociatedToken},
+    token_2022::Token2022,
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 use crate::state::*;
+use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
+#[instruction(hotel_id: u64)]
 pub struct InitializeHotel<'info> {
-    #[account(init, payer = owner, space = 8 + 32 + 100 + 2 + 1)]
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Hotel::LEN,
+        seeds = [b"hotel", owner.key().as_ref(), &hotel_id.to_le_bytes()],
+        bump
+    )]
+    pub hotel: Account<'info, Hotel>,
+    #[account(
+        mut,
+        seeds = [b"hotel_registry"],
+        bump = hotel_registry.bump
+    )]
+    pub hotel_registry: Account<'info, HotelRegistry>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + 32 + 1,
+        seeds = [b"hotel_registry_entry", hotel_registry.hotel_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub registry_entry: Account<'info, HotelRegistryEntry>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateHotel<'info> {
+    #[account(mut, has_one = owner @ ErrorCode::Unauthorized)]
+    pub hotel: Account<'info, Hotel>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeHotelOwner<'info> {
+    #[account(mut, has_one = owner @ ErrorCode::Unauthorized)]
+    pub hotel: Account<'info, Hotel>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptHotelOwner<'info> {
+    #[account(
+        mut,
+        constraint = hotel.pending_owner == Some(new_owner.key()) @ ErrorCode::Unauthorized
+    )]
+    pub hotel: Account<'info, Hotel>,
+    pub new_owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseHotel<'info> {
+    #[account(
+        mut,
+        has_one = owner @ ErrorCode::Unauthorized,
+        close = owner
+    )]
+    pub hotel: Account<'info, Hotel>,
+    #[account(
+        mut,
+        seeds = [b"hotel_registry_entry", hotel.registry_index.to_le_bytes().as_ref()],
+        bump = registry_entry.bump,
+        close = owner
+    )]
+    pub registry_entry: Account<'info, HotelRegistryEntry>,
+    /// Required once the hotel has been appraised
+    #[account(
+        mut,
+        seeds = [b"hotel_collateral", hotel.key().as_ref()],
+        bump = hotel_collateral.bump,
+        close = owner
+    )]
+    pub hotel_collateral: Option<Account<'info, HotelCollateral>>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeHotelRegistry<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 8 + 1,
+        seeds = [b"hotel_registry"],
+        bump
+    )]
+    pub hotel_registry: Account<'info, HotelRegistry>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyHotel<'info> {
+    #[account(mut)]
+    pub hotel: Account<'info, Hotel>,
+    /// CHECK: Receives the verification token; must be the hotel owner
+    #[account(address = hotel.owner)]
+    pub owner: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = protocol_config.verifiers.contains(&verifier.key()) @ ErrorCode::UnauthorizedVerifier
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+    /// CHECK: Created and initialized as a Token-2022 mint by the instruction
+    #[account(
+        mut,
+        seeds = [b"verification_mint", hotel.key().as_ref()],
+        bump
+    )]
+    pub verification_mint: UncheckedAccount<'info>,
+    /// CHECK: Created as the owner's associated token account by the instruction
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&owner.key(), &verification_mint.key(), &token_program.key())
+    )]
+    pub owner_token_account: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnverifyHotel<'info> {
+    #[account(mut)]
+    pub hotel: Account<'info, Hotel>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = protocol_config.verifiers.contains(&verifier.key()) @ ErrorCode::UnauthorizedVerifier
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"verification_mint", hotel.key().as_ref()],
+        bump
+    )]
+    pub verification_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = verification_mint,
+        token::authority = hotel.owner,
+        token::token_program = token_program
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeProtocolConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ProtocolConfig::LEN,
+        seeds = [b"protocol_config"],
+        bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    // Only the upgrade authority may claim the admin role, so deployment can't be front-run
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::CoconutRwa>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageVerifiers<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateProtocolConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptProtocolAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = protocol_config.pending_admin == Some(new_admin.key()) @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub new_admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"treasury", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = protocol_config,
+        token::token_program = token_program
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        seeds = [b"treasury", mint.key().as_ref()],
+        bump
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+    pub admin: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct BurnCocoTokens<'info> {
+    #[account(mut, seeds = [b"coco_mint"], bump = coco_mint.bump)]
+    pub coco_mint: Account<'info, CocoMint>,
+    #[account(mut, address = coco_mint.mint)]
+    pub coco_token_mint: InterfaceAccount<'info, Mint>,
+    /// Supplied to count the burn as repayment of COCO issued against this hotel
+    #[account(
+        mut,
+        seeds = [b"hotel_collateral", hotel_collateral.hotel.as_ref()],
+        bump = hotel_collateral.bump
+    )]
+    pub hotel_collateral: Option<Account<'info, HotelCollateral>>,
+    /// Hotel of `hotel_collateral`; only its owner or the COCO mint authority can repay
+    pub hotel: Option<Account<'info, Hotel>>,
+    #[account(
+        mut,
+        token::mint = coco_token_mint,
+        token::authority = holder,
+        token::token_program = token_program
+    )]
+    pub holder_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub holder: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeRedemptionReserve<'info> {
+    #[account(
+        seeds = [b"coco_mint"],
+        bump = coco_mint.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub coco_mint: Account<'info, CocoMint>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"redemption_reserve"],
+        bump,
+        token::mint = redemption_mint,
+        token::authority = coco_mint,
+        token::token_program = token_program
+    )]
+    pub redemption_reserve: InterfaceAccount<'info, TokenAccount>,
+    pub redemption_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RedeemCocoTokens<'info> {
+    #[account(mut, seeds = [b"coco_mint"], bump = coco_mint.bump)]
+    pub coco_mint: Box<Account<'info, CocoMint>>,
+    #[account(mut, address = coco_mint.mint)]
+    pub coco_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = coco_token_mint,
+        token::authority = holder,
+        token::token_program = token_program
+    )]
+    pub holder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = redemption_reserve.mint)]
+    pub redemption_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [b"redemption_reserve"],
+        bump
+    )]
+    pub redemption_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = redemption_mint,
+        token::authority = holder,
+        token::token_program = redemption_token_program
+    )]
+    pub holder_redemption_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub holder: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub redemption_token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: VestingParams)]
+pub struct CreateVesting<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Vesting::LEN,
+        seeds = [b"vesting", params.beneficiary.as_ref(), params.vesting_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vesting: Box<Account<'info, Vesting>>,
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"vesting_vault", vesting.key().as_ref()],
+        bump,
+        token::mint = coco_token_mint,
+        token::authority = vesting,
+        token::token_program = token_program
+    )]
+    pub vesting_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(seeds = [b"coco_mint"], bump = coco_mint.bump)]
+    pub coco_mint: Box<Account<'info, CocoMint>>,
+    #[account(address = coco_mint.mint)]
+    pub coco_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = coco_token_mint,
+        token::authority = creator,
+        token::token_program = token_program
+    )]
+    pub creator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: VestingParams)]
+pub struct IssueVestedCocoTokens<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Vesting::LEN,
+        seeds = [b"vesting", params.beneficiary.as_ref(), params.vesting_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vesting: Box<Account<'info, Vesting>>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"vesting_vault", vesting.key().as_ref()],
+        bump,
+        token::mint = coco_token_mint,
+        token::authority = vesting,
+        token::token_program = token_program
+    )]
+    pub vesting_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"coco_mint"],
+        bump = coco_mint.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub coco_mint: Box<Account<'info, CocoMint>>,
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    pub hotel: Box<Account<'info, Hotel>>,
+    #[account(
+        mut,
+        seeds = [b"hotel_collateral", hotel.key().as_ref()],
+        bump = hotel_collateral.bump,
+        has_one = hotel
+    )]
+    pub hotel_collateral: Box<Account<'info, HotelCollateral>>,
+    #[account(mut, address = coco_mint.mint)]
+    pub coco_token_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub authority: Signer<'info>,
+    /// Pays for the vesting accounts; see `IssueCococTokens::payer`
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    #[account(mut, has_one = beneficiary @ ErrorCode::Unauthorized)]
+    pub vesting: Account<'info, Vesting>,
+    #[account(
+        mut,
+        seeds = [b"vesting_vault", vesting.key().as_ref()],
+        bump
+    )]
+    pub vesting_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = vesting_vault.mint)]
+    pub coco_token_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = coco_token_mint,
+        token::authority = beneficiary,
+        token::token_program = token_program
+    )]
+    pub beneficiary_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub beneficiary: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeVesting<'info> {
+    #[account(mut)]
+    pub vesting: Account<'info, Vesting>,
+    #[account(
+        mut,
+        seeds = [b"vesting_vault", vesting.key().as_ref()],
+        bump
+    )]
+    pub vesting_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = vesting_vault.mint)]
+    pub coco_token_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        token::mint = coco_token_mint,
+        token::authority = vesting.creator,
+        token::token_program = token_program
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub admin: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Multisig::LEN,
+        seeds = [b"multisig", create_key.key().as_ref()],
+        bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    /// CHECK: Signs for the multisig; holds no data
+    #[account(seeds = [b"multisig_signer", multisig.key().as_ref()], bump)]
+    pub multisig_signer: UncheckedAccount<'info>,
+    /// Fresh key that makes the multisig address unique
+    pub create_key: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeMultisigTransaction<'info> {
+    #[account(mut, seeds = [b"multisig", multisig.create_key.as_ref()], bump = multisig.bump)]
+    pub multisig: Account<'info, Multisig>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + MultisigProposal::LEN,
+        seeds = [b"multisig_proposal", multisig.key().as_ref(), multisig.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Box<Account<'info, MultisigProposal>>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveMultisigTransaction<'info> {
+    #[account(seeds = [b"multisig", multisig.create_key.as_ref()], bump = multisig.bump)]
+    pub multisig: Account<'info, Multisig>,
+    #[account(mut, has_one = multisig)]
+    pub proposal: Box<Account<'info, MultisigProposal>>,
+    pub member: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteMultisigTransaction<'info> {
+    // Not mut: the proposed instruction may update the multisig itself
+    #[account(seeds = [b"multisig", multisig.create_key.as_ref()], bump = multisig.bump)]
+    pub multisig: Account<'info, Multisig>,
+    #[account(mut, has_one = multisig)]
+    pub proposal: Box<Account<'info, MultisigProposal>>,
+    pub member: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseMultisigProposal<'info> {
+    #[account(seeds = [b"multisig", multisig.create_key.as_ref()], bump = multisig.bump)]
+    pub multisig: Account<'info, Multisig>,
+    #[account(mut, has_one = multisig, has_one = proposer, close = proposer)]
+    pub proposal: Box<Account<'info, MultisigProposal>>,
+    /// CHECK: Receives the proposal's rent; must be its proposer
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetMultisigMembers<'info> {
+    #[account(mut, seeds = [b"multisig", multisig.create_key.as_ref()], bump = multisig.bump)]
+    pub multisig: Account<'info, Multisig>,
+    #[account(seeds = [b"multisig_signer", multisig.key().as_ref()], bump = multisig.signer_bump)]
+    pub multisig_signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AttestHotelAppraisal<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = protocol_config.verifiers.contains(&verifier.key()) @ ErrorCode::UnauthorizedVerifier
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(mut)]
+    pub hotel: Account<'info, Hotel>,
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = 8 + HotelCollateral::LEN,
+        seeds = [b"hotel_collateral", hotel.key().as_ref()],
+        bump
+    )]
+    pub hotel_collateral: Account<'info, HotelCollateral>,
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(decimals: u8)]
+pub struct InitializeCocoMint<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + CocoMint::LEN,
+        seeds = [b"coco_mint"],
+        bump
+    )]
+    pub coco_mint: Account<'info, CocoMint>,
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"coco_token_mint"],
+        bump,
+        mint::decimals = decimals,
+        mint::authority = coco_mint,
+        mint::token_program = token_program
+    )]
+    pub coco_token_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct IssueCococTokens<'info> {
+    #[account(
+        mut,
+        seeds = [b"coco_mint"],
+        bump = coco_mint.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub coco_mint: Account<'info, CocoMint>,
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub hotel: Account<'info, Hotel>,
+    #[account(
+        mut,
+        seeds = [b"hotel_collateral", hotel.key().as_ref()],
+        bump = hotel_collateral.bump,
+        has_one = hotel
+    )]
+    pub hotel_collateral: Account<'info, HotelCollateral>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = coco_token_mint,
+        associated_token::authority = authority
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, address = coco_mint.mint)]
+    pub coco_token_mint: InterfaceAccount<'info, Mint>,
+    pub authority: Signer<'info>,
+    /// Funds the token account, so a PDA authority such as a multisig signer doesn't have to
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCocoMint<'info> {
+    #[account(
+        mut,
+        seeds = [b"coco_mint"],
+        bump = coco_mint.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub coco_mint: Account<'info, CocoMint>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptCocoMintAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"coco_mint"],
+        bump = coco_mint.bump,
+        constraint = coco_mint.pending_authority == Some(new_authority.key()) @ ErrorCode::Unauthorized
+    )]
+    pub coco_mint: Account<'info, CocoMint>,
+    pub new_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateLiquidityPool<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + LiquidityPool::LEN,
+        seeds = [b"liquidity_pool", coco_mint.key().as_ref(), usdc_mint.key().as_ref()],
+        bump
+    )]
+    pub liquidity_pool: Box<Account<'info, LiquidityPool>>,
+    pub coco_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"pool_vault", liquidity_pool.key().as_ref(), coco_mint.key().as_ref()],
+        bump,
+        token::mint = coco_mint,
+        token::authority = liquidity_pool,
+        token::token_program = coco_token_program
+    )]
+    pub coco_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"pool_vault", liquidity_pool.key().as_ref(), usdc_mint.key().as_ref()],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = liquidity_pool,
+        token::token_program = usdc_token_program
+    )]
+    pub usdc_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    // Pools are used as a price source for bookings, so only the protocol admin may create them
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = protocol_config.admin == creator.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub coco_token_program: Interface<'info, TokenInterface>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    #[account(mut, has_one = coco_mint, has_one = usdc_mint)]
+    pub liquidity_pool: Box<Account<'info, LiquidityPool>>,
+    pub coco_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [b"pool_vault", liquidity_pool.key().as_ref(), coco_mint.key().as_ref()],
+        bump
+    )]
+    pub coco_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"pool_vault", liquidity_pool.key().as_ref(), usdc_mint.key().as_ref()],
+        bump
+    )]
+    pub usdc_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        token::mint = coco_mint,
+        token::authority = user,
+        token::token_program = coco_token_program
+    )]
+    pub coco_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = user,
+        token::token_program = usdc_token_program
+    )]
+    pub usdc_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub coco_token_program: Interface<'info, TokenInterface>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct SwapTokens<'info> {
+    #[account(mut, has_one = coco_mint, has_one = usdc_mint)]
+    pub liquidity_pool: Box<Account<'info, LiquidityPool>>,
+    pub coco_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [b"pool_vault", liquidity_pool.key().as_ref(), coco_mint.key().as_ref()],
+        bump
+    )]
+    pub coco_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"pool_vault", liquidity_pool.key().as_ref(), usdc_mint.key().as_ref()],
+        bump
+    )]
+    pub usdc_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    /// Must hold one of the pool's mints; `token_account_out` must hold the other
+    #[account(
+        mut,
+        token::authority = user,
+        constraint = token_account_in.mint == coco_mint.key() || token_account_in.mint == usdc_mint.key()
+            @ ErrorCode::InvalidPaymentMint
+    )]
+    pub token_account_in: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = token_account_out.mint != token_account_in.mint
+            && (token_account_out.mint == coco_mint.key() || token_account_out.mint == usdc_mint.key())
+            @ ErrorCode::InvalidPaymentMint
+    )]
+    pub token_account_out: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub coco_token_program: Interface<'info, TokenInterface>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct SyncPoolPrice<'info> {
+    #[account(mut)]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateLiquidityPool<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptPoolAuthority<'info> {
+    #[account(
+        mut,
+        constraint = liquidity_pool.pending_authority == Some(new_authority.key()) @ ErrorCode::Unauthorized
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+    pub new_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct StakeCocoTokens<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+    #[account(mut, seeds = [b"revenue_distributor"], bump = revenue_distributor.bump)]
+    pub revenue_distributor: Account<'info, RevenueDistributor>,
+    #[account(address = revenue_distributor.stake_mint)]
+    pub coco_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = coco_mint,
+        token::authority = staker,
+        token::token_program = token_program
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = 8 + StakeAccount::LEN,
+        seeds = [b"stake", staker.key().as_ref()],
+        bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+    #[account(
+        init_if_needed,
+        payer = staker,
+        seeds = [b"stake_vault", staker.key().as_ref()],
+        bump,
+        token::mint = coco_mint,
+        token::authority = stake_account,
+        token::token_program = token_program
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnstakeCocoTokens<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+    #[account(mut, seeds = [b"revenue_distributor"], bump = revenue_distributor.bump)]
+    pub revenue_distributor: Account<'info, RevenueDistributor>,
+    #[account(address = revenue_distributor.stake_mint)]
+    pub coco_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = coco_mint,
+        token::authority = staker,
+        token::token_program = token_program
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"stake", staker.key().as_ref()],
+        bump = stake_account.bump,
+        constraint = stake_account.owner == staker.key() @ ErrorCode::Unauthorized
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+    #[account(
+        mut,
+        seeds = [b"stake_vault", staker.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeRevenueDistributor<'info> {
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + RevenueDistributor::LEN,
+        seeds = [b"revenue_distributor"],
+        bump
+    )]
+    pub revenue_distributor: Account<'info, RevenueDistributor>,
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"revenue_vault"],
+        bump,
+        token::mint = reward_mint,
+        token::authority = revenue_distributor,
+        token::token_program = token_program
+    )]
+    pub revenue_vault: InterfaceAccount<'info, TokenAccount>,
+    pub stake_mint: InterfaceAccount<'info, Mint>,
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRevenue<'info> {
+    pub staker: Signer<'info>,
+    #[account(seeds = [b"revenue_distributor"], bump = revenue_distributor.bump)]
+    pub revenue_distributor: Account<'info, RevenueDistributor>,
+    #[account(
+        mut,
+        seeds = [b"stake", staker.key().as_ref()],
+        bump = stake_account.bump,
+        constraint = stake_account.owner == staker.key() @ ErrorCode::Unauthorized
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+    #[account(address = revenue_distributor.reward_mint)]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"revenue_vault"],
+        bump
+    )]
+    pub revenue_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = staker,
+        token::token_program = token_program
+    )]
+    pub staker_reward_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct CreateRoomType<'info> {
+    #[account(mut, has_one = owner @ ErrorCode::Unauthorized)]
+    pub hotel: Account<'info, Hotel>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + RoomType::LEN,
+        seeds = [b"room_type", hotel.key().as_ref(), hotel.room_type_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub room_type: Account<'info, RoomType>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRoomType<'info> {
+    #[account(has_one = owner @ ErrorCode::Unauthorized)]
+    pub hotel: Account<'info, Hotel>,
+    #[account(mut, has_one = hotel)]
+    pub room_type: Account<'info, RoomType>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(room_number: u16)]
+pub struct CreateRoom<'info> {
+    #[account(mut, has_one = owner @ ErrorCode::Unauthorized)]
+    pub hotel: Account<'info, Hotel>,
+    #[account(mut, has_one = hotel)]
+    pub room_type: Account<'info, RoomType>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + 32 + 2 + 32 + 1,
+        seeds = [b"room", hotel.key().as_ref(), room_number.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub room: Account<'info, Room>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseRoom<'info> {
+    #[account(mut, has_one = owner @ ErrorCode::Unauthorized)]
+    pub hotel: Account<'info, Hotel>,
+    #[account(mut, address = room.room_type)]
+    pub room_type: Account<'info, RoomType>,
+    #[account(mut, has_one = hotel, close = owner)]
+    pub room: Account<'info, Room>,
+    /// CHECK: The room's listing address; must not hold a listing
+    #[account(seeds = [b"rental_listing", room.key().as_ref()], bump)]
+    pub rental_listing: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseRoomType<'info> {
+    #[account(mut, has_one = owner @ ErrorCode::Unauthorized)]
+    pub hotel: Account<'info, Hotel>,
+    #[account(mut, has_one = hotel, close = owner)]
+    pub room_type: Account<'info, RoomType>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(check_in_day: u32)]
+pub struct BookRoom<'info> {
+    #[account(mut)]
+    pub hotel: Box<Account<'info, Hotel>>,
+    #[account(has_one = hotel)]
+    pub room: Account<'info, Room>,
+    #[account(address = room.room_type)]
+    pub room_type: Box<Account<'info, RoomType>>,
+    #[account(
+        seeds = [b"rental_listing", room.key().as_ref()],
+        bump = rental_listing.bump,
+        has_one = hotel,
+        has_one = room
+    )]
+    pub rental_listing: Box<Account<'info, RentalListing>>,
+    #[account(
+        seeds = [b"pricing_rules", rental_listing.key().as_ref()],
+        bump = pricing_rules.bump,
+        has_one = rental_listing
+    )]
+    pub pricing_rules: Option<Box<Account<'info, PricingRules>>>,
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    #[account(seeds = [b"revenue_distributor"], bump = revenue_distributor.bump)]
+    pub revenue_distributor: Box<Account<'info, RevenueDistributor>>,
+    #[account(
+        init_if_needed,
+        payer = renter,
+        space = 8 + 32 + 4 + 4 + 1,
+        seeds = [b"room_calendar", room.key().as_ref(), (check_in_day / CALENDAR_WINDOW_DAYS).to_le_bytes().as_ref()],
+        bump
+    )]
+    pub calendar: Account<'info, RoomCalendar>,
+    /// Required only when the stay runs into the next calendar window
+    #[account(
+        init_if_needed,
+        payer = renter,
+        space = 8 + 32 + 4 + 4 + 1,
+        seeds = [b"room_calendar", room.key().as_ref(), (check_in_day / CALENDAR_WINDOW_DAYS + 1).to_le_bytes().as_ref()],
+        bump
+    )]
+    pub next_calendar: Option<Account<'info, RoomCalendar>>,
+    #[account(
+        init,
+        payer = renter,
+        space = 8 + Booking::LEN,
+        seeds = [b"booking", room.key().as_ref(), check_in_day.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub booking: Box<Account<'info, Booking>>,
+    #[account(mut)]
+    pub renter: Signer<'info>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = renter,
+        token::token_program = token_program
+    )]
+    pub renter_payment_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// The listing's mint, or the pool's COCO mint when paying in COCO
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    /// Supplied to pay in COCO at the pool's TWAP; must pair COCO with the listing's mint
+    #[account(
+        seeds = [b"liquidity_pool", liquidity_pool.coco_mint.as_ref(), liquidity_pool.usdc_mint.as_ref()],
+        bump = liquidity_pool.bump
+    )]
+    pub liquidity_pool: Option<Box<Account<'info, LiquidityPool>>>,
+    /// Supplied by stakers paying in COCO to receive their discount tier
+    #[account(
+        seeds = [b"stake", renter.key().as_ref()],
+        bump,
+        constraint = stake_account.owner == renter.key() @ ErrorCode::Unauthorized
+    )]
+    pub stake_account: Option<Account<'info, StakeAccount>>,
+    #[account(
+        init,
+        payer = renter,
+        seeds = [b"escrow", booking.key().as_ref()],
+        bump,
+        token::mint = payment_mint,
+        token::authority = booking,
+        token::token_program = token_program
+    )]
+    pub escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Created and initialized as a Token-2022 mint by the instruction
+    #[account(
+        mut,
+        seeds = [b"booking_receipt", booking.key().as_ref()],
+        bump
+    )]
+    pub receipt_mint: UncheckedAccount<'info>,
+    /// CHECK: Created as the renter's associated token account by the instruction
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&renter.key(), &receipt_mint.key(), &receipt_token_program.key())
+    )]
+    pub renter_receipt_account: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub receipt_token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CheckIn<'info> {
+    pub hotel: Account<'info, Hotel>,
+    #[account(mut, has_one = hotel, has_one = renter)]
+    pub booking: Account<'info, Booking>,
+    pub renter: Signer<'info>,
+    #[account(address = booking.payment_mint)]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"escrow", booking.key().as_ref()],
+        bump
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = hotel.owner,
+        token::token_program = token_program
+    )]
+    pub hotel_payout_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"treasury", payment_mint.key().as_ref()],
+        bump
+    )]
+    pub treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"revenue_distributor"], bump = revenue_distributor.bump)]
+    pub revenue_distributor: Box<Account<'info, RevenueDistributor>>,
+    /// CHECK: Only receives transfers when the booking has a revenue share, which requires
+    /// the payment mint to match the vault's
+    #[account(mut, seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"hotel_shares", hotel.key().as_ref()],
+        bump = hotel_shares.bump
+    )]
+    pub hotel_shares: Option<Box<Account<'info, HotelShares>>>,
+    /// CHECK: Only receives transfers when the booking is paid in the shares' income mint,
+    /// which then matches the vault's
+    #[account(mut, seeds = [b"hotel_income_vault", hotel.key().as_ref()], bump)]
+    pub hotel_income_vault: Option<UncheckedAccount<'info>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct CheckOut<'info> {
+    #[account(mut)]
+    pub hotel: Account<'info, Hotel>,
+    #[account(
+        mut,
+        has_one = hotel,
+        has_one = renter,
+        constraint = signer.key() == booking.renter || signer.key() == hotel.owner @ ErrorCode::Unauthorized
+    )]
+    pub booking: Account<'info, Booking>,
+    pub signer: Signer<'info>,
+    /// CHECK: Receives the escrow account rent; must be the booking renter
+    #[account(mut)]
+    pub renter: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"escrow", booking.key().as_ref()],
+        bump
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"booking_receipt", booking.key().as_ref()],
+        bump
+    )]
+    pub receipt_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = receipt_mint,
+        token::authority = booking.renter,
+        token::token_program = receipt_token_program,
+        constraint = receipt_token_account.amount == 1 @ ErrorCode::InvalidReceiptAccount
+    )]
+    pub receipt_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub receipt_token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct CancelBooking<'info> {
+    #[account(mut)]
+    pub hotel: Box<Account<'info, Hotel>>,
+    #[account(
+        mut,
+        has_one = hotel,
+        has_one = renter,
+        close = renter
+    )]
+    pub booking: Box<Account<'info, Booking>>,
+    #[account(mut)]
+    pub renter: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"room_calendar", booking.room.as_ref(), (booking.check_in_day / CALENDAR_WINDOW_DAYS).to_le_bytes().as_ref()],
+        bump = calendar.bump
+    )]
+    pub calendar: Account<'info, RoomCalendar>,
+    /// Required only when the stay runs into the next calendar window
+    #[account(
+        mut,
+        seeds = [b"room_calendar", booking.room.as_ref(), (booking.check_in_day / CALENDAR_WINDOW_DAYS + 1).to_le_bytes().as_ref()],
+        bump = next_calendar.bump
+    )]
+    pub next_calendar: Option<Account<'info, RoomCalendar>>,
+    #[account(address = booking.payment_mint)]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"escrow", booking.key().as_ref()],
+        bump
+    )]
+    pub escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = renter,
+        token::token_program = token_program
+    )]
+    pub renter_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = hotel.owner,
+        token::token_program = token_program
+    )]
+    pub hotel_payout_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"treasury", payment_mint.key().as_ref()],
+        bump
+    )]
+    pub treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"revenue_distributor"], bump = revenue_distributor.bump)]
+    pub revenue_distributor: Box<Account<'info, RevenueDistributor>>,
+    /// CHECK: Only receives transfers when the booking has a revenue share, which requires
+    /// the payment mint to match the vault's
+    #[account(mut, seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"hotel_shares", hotel.key().as_ref()],
+        bump = hotel_shares.bump
+    )]
+    pub hotel_shares: Option<Box<Account<'info, HotelShares>>>,
+    /// CHECK: Only receives transfers when the booking is paid in the shares' income mint,
+    /// which then matches the vault's
+    #[account(mut, seeds = [b"hotel_income_vault", hotel.key().as_ref()], bump)]
+    pub hotel_income_vault: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        seeds = [b"booking_receipt", booking.key().as_ref()],
+        bump
+    )]
+    pub receipt_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = receipt_mint,
+        token::authority = booking.renter,
+        token::token_program = receipt_token_program,
+        constraint = receipt_token_account.amount == 1 @ ErrorCode::InvalidReceiptAccount
+    )]
+    pub receipt_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub receipt_token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimNoShowRefund<'info> {
+    #[account(mut)]
     pub hotel: Account<'info, Hotel>,
+    #[account(
+        mut,
+        has_one = hotel,
+        has_one = renter,
+        close = renter
+    )]
+    pub booking: Account<'info, Booking>,
     #[account(mut)]
-    pub owner: Signer<'info>,
-    pub system_program: Program<'info, System>,
+    pub renter: Signer<'info>,
+    #[account(address = booking.payment_mint)]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"escrow", booking.key().as_ref()],
+        bump
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = renter,
+        token::token_program = token_program
+    )]
+    pub renter_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"booking_receipt", booking.key().as_ref()],
+        bump
+    )]
+    pub receipt_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = receipt_mint,
+        token::authority = booking.renter,
+        token::token_program = receipt_token_program,
+        constraint = receipt_token_account.amount == 1 @ ErrorCode::InvalidReceiptAccount
+    )]
+    pub receipt_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub receipt_token_program: Program<'info, Token2022>,
 }
 
 #[derive(Accounts)]
-pub struct VerifyHotel<'info> {
-    #[account(mut, has_one = owner)]
+pub struct ReleaseDeposit<'info> {
+    #[account(mut)]
+    pub hotel: Account<'info, Hotel>,
+    #[account(mut, has_one = hotel, has_one = renter)]
+    pub booking: Account<'info, Booking>,
+    /// CHECK: Receives the escrow account rent; must be the booking renter
+    #[account(mut)]
+    pub renter: UncheckedAccount<'info>,
+    #[account(address = booking.payment_mint)]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"escrow", booking.key().as_ref()],
+        bump
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = renter,
+        token::token_program = token_program
+    )]
+    pub renter_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimDamage<'info> {
+    #[account(has_one = owner @ ErrorCode::Unauthorized)]
     pub hotel: Account<'info, Hotel>,
+    #[account(mut, has_one = hotel)]
+    pub booking: Account<'info, Booking>,
     pub owner: Signer<'info>,
-    /// CHECK: This is the authority that can verify hotels
-    pub authority: UncheckedAccount<'info>,
 }
 
 #[derive(Accounts)]
-pub struct IssueCococTokens<'info> {
-    #[account(mut)]
-    pub coco_mint: Account<'info, CocoMint>,
+pub struct ResolveDamageClaim<'info> {
     #[account(
-        init_if_needed,
-        payer = authority,
-        associated_token::mint = coco_token_mint,
-        associated_token::authority = authority
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = protocol_config.verifiers.contains(&verifier.key()) @ ErrorCode::UnauthorizedVerifier
     )]
-    pub token_account: InterfaceAccount<'info, TokenAccount>,
-    pub coco_token_mint: InterfaceAccount<'info, Mint>,
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    pub verifier: Signer<'info>,
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub hotel: Box<Account<'info, Hotel>>,
+    #[account(mut, has_one = hotel, has_one = renter)]
+    pub booking: Box<Account<'info, Booking>>,
+    /// CHECK: Receives the escrow account rent; must be the booking renter
+    #[account(mut)]
+    pub renter: UncheckedAccount<'info>,
+    #[account(address = booking.payment_mint)]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [b"escrow", booking.key().as_ref()],
+        bump
+    )]
+    pub escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = renter,
+        token::token_program = token_program
+    )]
+    pub renter_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = hotel.owner,
+        token::token_program = token_program
+    )]
+    pub hotel_payout_account: Box<InterfaceAccount<'info, TokenAccount>>,
     pub token_program: Interface<'info, TokenInterface>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct CreateLiquidityPool<'info> {
-    #[account(init, payer = creator, space = 8 + 8 + 8 + 8)]
-    pub liquidity_pool: Account<'info, LiquidityPool>,
+pub struct SubmitReview<'info> {
+    #[account(
+        init,
+        payer = reviewer,
+        space = 8 + 32 + 32 + 32 + 1 + 32 + 8 + 1,
+        seeds = [b"review", booking.key().as_ref()],
+        bump
+    )]
+    pub review: Account<'info, Review>,
     #[account(mut)]
-    pub creator: Signer<'info>,
+    pub hotel: Account<'info, Hotel>,
+    #[account(
+        has_one = hotel,
+        constraint = booking.renter == reviewer.key() @ ErrorCode::Unauthorized
+    )]
+    pub booking: Account<'info, Booking>,
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct AddLiquidity<'info> {
-    #[account(mut)]
-    pub liquidity_pool: Account<'info, LiquidityPool>,
-    #[account(mut)]
-    pub user: Signer<'info>,
-    #[account(mut)]
-    pub coco_token_account: InterfaceAccount<'info, TokenAccount>,
+#[instruction(decimals: u8)]
+pub struct CreateHotelShares<'info> {
+    #[account(mut, has_one = owner @ ErrorCode::Unauthorized)]
+    pub hotel: Box<Account<'info, Hotel>>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + HotelShares::LEN,
+        seeds = [b"hotel_shares", hotel.key().as_ref()],
+        bump
+    )]
+    pub hotel_shares: Box<Account<'info, HotelShares>>,
+    #[account(
+        init,
+        payer = owner,
+        seeds = [b"hotel_share_mint", hotel.key().as_ref()],
+        bump,
+        mint::decimals = decimals,
+        mint::authority = hotel_shares,
+        mint::token_program = token_program
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// Holds shares deposited by investors to earn income
+    #[account(
+        init,
+        payer = owner,
+        seeds = [b"hotel_share_vault", hotel.key().as_ref()],
+        bump,
+        token::mint = share_mint,
+        token::authority = hotel_shares,
+        token::token_program = token_program
+    )]
+    pub share_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub income_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        payer = owner,
+        seeds = [b"hotel_income_vault", hotel.key().as_ref()],
+        bump,
+        token::mint = income_mint,
+        token::authority = hotel_shares,
+        token::token_program = token_program
+    )]
+    pub hotel_income_vault: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(mut)]
-    pub usdc_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub owner: Signer<'info>,
     pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct SwapTokens<'info> {
-    #[account(mut)]
-    pub liquidity_pool: Account<'info, LiquidityPool>,
-    #[account(mut)]
-    pub user: Signer<'info>,
-    #[account(mut)]
-    pub token_account_in: InterfaceAccount<'info, TokenAccount>,
+pub struct IssueHotelShares<'info> {
+    #[account(has_one = owner @ ErrorCode::Unauthorized)]
+    pub hotel: Account<'info, Hotel>,
+    #[account(
+        mut,
+        seeds = [b"hotel_shares", hotel.key().as_ref()],
+        bump = hotel_shares.bump,
+        has_one = hotel,
+        has_one = share_mint
+    )]
+    pub hotel_shares: Account<'info, HotelShares>,
     #[account(mut)]
-    pub token_account_out: InterfaceAccount<'info, TokenAccount>,
+    pub share_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = share_mint,
+        token::token_program = token_program
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub owner: Signer<'info>,
     pub token_program: Interface<'info, TokenInterface>,
 }
 
 #[derive(Accounts)]
-pub struct StakeCocoTokens<'info> {
-    #[account(mut)]
-    pub staker: Signer<'info>,
-    #[account(mut)]
-    pub token_account: InterfaceAccount<'info, TokenAccount>,
+pub struct DepositHotelShares<'info> {
+    #[account(
+        mut,
+        has_one = share_mint
+    )]
+    pub hotel_shares: Box<Account<'info, HotelShares>>,
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [b"hotel_share_vault", hotel_shares.hotel.as_ref()],
+        bump
+    )]
+    pub share_vault: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         init_if_needed,
-        payer = staker,
-        space = 8 + 32 + 8 + 8,
-        seeds = [b"stake", staker.key().as_ref()],
+        payer = investor,
+        space = 8 + HotelSharePosition::LEN,
+        seeds = [b"share_position", hotel_shares.key().as_ref(), investor.key().as_ref()],
         bump
     )]
-    pub stake_account: Account<'info, StakeAccount>,
+    pub position: Box<Account<'info, HotelSharePosition>>,
+    #[account(
+        mut,
+        token::mint = share_mint,
+        token::authority = investor,
+        token::token_program = token_program
+    )]
+    pub investor_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub investor: Signer<'info>,
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct UnstakeCocoTokens<'info> {
-    #[account(mut)]
-    pub staker: Signer<'info>,
-    #[account(mut)]
-    pub token_account: InterfaceAccount<'info, TokenAccount>,
+pub struct WithdrawHotelShares<'info> {
     #[account(
         mut,
-        seeds = [b"stake", staker.key().as_ref()],
-        bump,
-        has_one = owner
+        has_one = share_mint
     )]
-    pub stake_account: Account<'info, StakeAccount>,
+    pub hotel_shares: Box<Account<'info, HotelShares>>,
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [b"hotel_share_vault", hotel_shares.hotel.as_ref()],
+        bump
+    )]
+    pub share_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"share_position", hotel_shares.key().as_ref(), investor.key().as_ref()],
+        bump = position.bump,
+        has_one = hotel_shares
+    )]
+    pub position: Box<Account<'info, HotelSharePosition>>,
+    #[account(
+        mut,
+        token::mint = share_mint,
+        token::authority = investor,
+        token::token_program = token_program
+    )]
+    pub investor_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub investor: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimHotelIncome<'info> {
+    #[account(has_one = income_mint)]
+    pub hotel_shares: Box<Account<'info, HotelShares>>,
+    pub income_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [b"hotel_income_vault", hotel_shares.hotel.as_ref()],
+        bump
+    )]
+    pub hotel_income_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"share_position", hotel_shares.key().as_ref(), investor.key().as_ref()],
+        bump = position.bump,
+        has_one = hotel_shares
+    )]
+    pub position: Box<Account<'info, HotelSharePosition>>,
+    #[account(
+        mut,
+        token::mint = income_mint,
+        token::authority = investor,
+        token::token_program = token_program
+    )]
+    pub investor_income_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub investor: Signer<'info>,
     pub token_program: Interface<'info, TokenInterface>,
 }
 
 #[derive(Accounts)]
-pub struct RentRoom<'info> {
+pub struct ListBookingForResale<'info> {
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + 32 + 32 + 8 + 1,
+        seeds = [b"resale", booking.key().as_ref()],
+        bump
+    )]
+    pub resale_listing: Account<'info, ResaleListing>,
+    #[account(constraint = booking.renter == seller.key() @ ErrorCode::Unauthorized)]
+    pub booking: Account<'info, Booking>,
     #[account(mut)]
-    pub hotel: Account<'info, Hotel>,
+    pub seller: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelResaleListing<'info> {
+    #[account(mut, has_one = seller @ ErrorCode::Unauthorized, close = seller)]
+    pub resale_listing: Account<'info, ResaleListing>,
     #[account(mut)]
-    pub renter: Signer<'info>,
+    pub seller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BuyResaleBooking<'info> {
+    #[account(
+        mut,
+        has_one = booking,
+        has_one = seller,
+        close = seller
+    )]
+    pub resale_listing: Box<Account<'info, ResaleListing>>,
+    pub hotel: Box<Account<'info, Hotel>>,
+    #[account(
+        mut,
+        has_one = hotel,
+        constraint = booking.renter == seller.key() @ ErrorCode::Unauthorized
+    )]
+    pub booking: Box<Account<'info, Booking>>,
+    /// CHECK: Receives the sale proceeds and listing rent; must be the listing's seller
     #[account(mut)]
-    pub renter_usdc_account: InterfaceAccount<'info, TokenAccount>,
+    pub seller: UncheckedAccount<'info>,
     #[account(mut)]
-    pub hotel_owner_usdc_account: InterfaceAccount<'info, TokenAccount>,
+    pub buyer: Signer<'info>,
+    #[account(address = booking.payment_mint)]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = buyer,
+        token::token_program = token_program
+    )]
+    pub buyer_payment_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = seller,
+        token::token_program = token_program
+    )]
+    pub seller_payment_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = hotel.owner,
+        token::token_program = token_program
+    )]
+    pub hotel_payout_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [b"booking_receipt", booking.key().as_ref()],
+        bump
+    )]
+    pub receipt_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = receipt_mint,
+        token::authority = seller,
+        token::token_program = receipt_token_program,
+        constraint = seller_receipt_account.amount == 1 @ ErrorCode::InvalidReceiptAccount
+    )]
+    pub seller_receipt_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Created as the buyer's associated token account by the instruction if needed
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&buyer.key(), &receipt_mint.key(), &receipt_token_program.key())
+    )]
+    pub buyer_receipt_account: UncheckedAccount<'info>,
     pub token_program: Interface<'info, TokenInterface>,
+    pub receipt_token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
+#[instruction(room_number: u16)]
 pub struct CreateRentalListing<'info> {
     #[account(
         init,
         payer = owner,
-        space = 8 + 32 + 2 + 8 + 1
+        space = 8 + 32 + 32 + 32 + 2 + 32 + 8 + 1 + 1 + 1,
+        seeds = [b"rental_listing", room.key().as_ref()],
+        bump
     )]
     pub rental_listing: Account<'info, RentalListing>,
+    #[account(mut, has_one = owner @ ErrorCode::Unauthorized)]
+    pub hotel: Account<'info, Hotel>,
+    #[account(
+        seeds = [b"room", hotel.key().as_ref(), room_number.to_le_bytes().as_ref()],
+        bump = room.bump
+    )]
+    pub room: Account<'info, Room>,
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRentalListing<'info> {
+    #[account(has_one = owner @ ErrorCode::Unauthorized)]
+    pub hotel: Account<'info, Hotel>,
+    #[account(mut, has_one = hotel)]
+    pub rental_listing: Account<'info, RentalListing>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPricingRules<'info> {
+    #[account(has_one = owner @ ErrorCode::Unauthorized)]
+    pub hotel: Account<'info, Hotel>,
+    #[account(mut, has_one = hotel)]
+    pub rental_listing: Account<'info, RentalListing>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + PricingRules::LEN,
+        seeds = [b"pricing_rules", rental_listing.key().as_ref()],
+        bump
+    )]
+    pub pricing_rules: Account<'info, PricingRules>,
     #[account(mut)]
     pub owner: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct QuoteBooking<'info> {
+    pub rental_listing: Account<'info, RentalListing>,
+    #[account(
+        seeds = [b"pricing_rules", rental_listing.key().as_ref()],
+        bump = pricing_rules.bump,
+        has_one = rental_listing
+    )]
+    pub pricing_rules: Option<Account<'info, PricingRules>>,
+}
+
+#[derive(Accounts)]
+pub struct ClearPricingRules<'info> {
+    #[account(has_one = owner @ ErrorCode::Unauthorized)]
+    pub hotel: Account<'info, Hotel>,
+    #[account(mut, has_one = hotel)]
+    pub rental_listing: Account<'info, RentalListing>,
+    #[account(
+        mut,
+        seeds = [b"pricing_rules", rental_listing.key().as_ref()],
+        bump = pricing_rules.bump,
+        has_one = rental_listing,
+        close = owner
+    )]
+    pub pricing_rules: Account<'info, PricingRules>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseRentalListing<'info> {
+    #[account(mut, has_one = owner @ ErrorCode::Unauthorized)]
+    pub hotel: Account<'info, Hotel>,
+    #[account(mut, has_one = hotel, close = owner)]
+    pub rental_listing: Account<'info, RentalListing>,
+    #[account(
+        mut,
+        seeds = [b"pricing_rules", rental_listing.key().as_ref()],
+        bump = pricing_rules.bump,
+        has_one = rental_listing,
+        close = owner
+    )]
+    pub pricing_rules: Option<Account<'info, PricingRules>>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct ConfidentialTransfer<'info> {
     #[account(mut)]
@@ -26,4 +26,144 @@ pub enum ErrorCode {
     InvalidRoomCount,
     #[msg("Confidential transfer failed")]
     ConfidentialTransferFailed,
+    #[msg("Signer is not a registered hotel verifier")]
+    UnauthorizedVerifier,
+    #[msg("Verifier is already registered")]
+    VerifierAlreadyRegistered,
+    #[msg("Verifier is not registered")]
+    VerifierNotRegistered,
+    #[msg("Maximum number of verifiers reached")]
+    TooManyVerifiers,
+    #[msg("Hotel is already verified")]
+    HotelAlreadyVerified,
+    #[msg("Hotel is not verified")]
+    HotelNotVerified,
+    #[msg("Hotel name must be between 1 and 64 bytes")]
+    InvalidHotelName,
+    #[msg("Hotel verification must be revoked first")]
+    HotelStillVerified,
+    #[msg("Hotel still has rental listings")]
+    HotelHasActiveListings,
+    #[msg("Room type name must be between 1 and 32 bytes")]
+    InvalidRoomTypeName,
+    #[msg("Room capacity must be greater than zero")]
+    InvalidRoomCapacity,
+    #[msg("Room is already booked for one or more of the requested nights")]
+    RoomUnavailable,
+    #[msg("Stay must start today or later and last between 1 and 30 nights")]
+    InvalidStayDates,
+    #[msg("Hotel still has active bookings")]
+    HotelHasActiveBookings,
+    #[msg("Booking is not in the required status")]
+    InvalidBookingStatus,
+    #[msg("Check-in is not open yet for this booking")]
+    CheckInNotOpen,
+    #[msg("The stay has not ended yet")]
+    StayNotEnded,
+    #[msg("Invalid cancellation policy")]
+    InvalidCancellationPolicy,
+    #[msg("Bookings can only be cancelled before check-in")]
+    CancellationWindowClosed,
+    #[msg("Rental listing is not active")]
+    ListingInactive,
+    #[msg("Payment mint does not match the listing")]
+    InvalidPaymentMint,
+    #[msg("Rental listing is already active")]
+    ListingAlreadyActive,
+    #[msg("Invalid pricing rules")]
+    InvalidPricingRules,
+    #[msg("Pricing rules account does not match the listing")]
+    PricingRulesMismatch,
+    #[msg("Token account does not hold the booking receipt")]
+    InvalidReceiptAccount,
+    #[msg("Royalty cannot exceed 100%")]
+    InvalidRoyalty,
+    #[msg("Booking receipts for this stay are not transferable")]
+    BookingNotResellable,
+    #[msg("Resale price must be greater than zero")]
+    InvalidResalePrice,
+    #[msg("Damage claim window cannot exceed 30 days")]
+    InvalidDamageClaimWindow,
+    #[msg("Security deposit is not in the required status")]
+    InvalidDepositStatus,
+    #[msg("The damage claim window has closed")]
+    DamageClaimWindowClosed,
+    #[msg("The damage claim window is still open")]
+    DamageClaimWindowOpen,
+    #[msg("Damage claim exceeds the security deposit")]
+    InvalidDamageClaim,
+    #[msg("Rating must be between 1 and 5")]
+    InvalidRating,
+    #[msg("Protocol fee cannot exceed 10%")]
+    InvalidProtocolFee,
+    #[msg("Revenue share cannot exceed 20%")]
+    InvalidRevenueShare,
+    #[msg("No revenue to claim")]
+    NothingToClaim,
+    #[msg("Hotel shares account does not match the hotel")]
+    HotelSharesMismatch,
+    #[msg("Issuing would exceed the maximum share supply")]
+    ShareSupplyExceeded,
+    #[msg("Hotel has issued shares and cannot be closed")]
+    HotelHasShares,
+    #[msg("Insufficient deposited shares")]
+    InsufficientShares,
+    #[msg("Income share cannot exceed 100%")]
+    InvalidIncomeShare,
+    #[msg("Maximum share supply must be greater than zero")]
+    InvalidShareSupply,
+    #[msg("Issuing would exceed the maximum COCO supply")]
+    MaxSupplyExceeded,
+    #[msg("Issuing would exceed this epoch's COCO allowance")]
+    EpochAllowanceExceeded,
+    #[msg("Invalid COCO emission schedule")]
+    InvalidEmissionSchedule,
+    #[msg("Issuing would exceed the hotel's loan-to-value limit")]
+    CollateralLimitExceeded,
+    #[msg("Appraised value must be greater than zero")]
+    InvalidAppraisal,
+    #[msg("Loan-to-value ratio cannot exceed 100%")]
+    InvalidLtv,
+    #[msg("Burn exceeds the COCO outstanding against the hotel")]
+    RepaymentExceedsIssued,
+    #[msg("Redemption amount is too small to receive any reserve funds")]
+    RedemptionTooSmall,
+    #[msg("Invalid vesting schedule")]
+    InvalidVestingSchedule,
+    #[msg("Vesting schedule is not revocable")]
+    VestingNotRevocable,
+    #[msg("Vesting schedule has already been revoked")]
+    VestingAlreadyRevoked,
+    #[msg("Invalid multisig members or threshold")]
+    InvalidMultisigConfig,
+    #[msg("Signer is not a member of the multisig")]
+    NotMultisigMember,
+    #[msg("Proposed instruction exceeds the maximum size")]
+    ProposalTooLarge,
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    #[msg("Multisig members changed since the proposal was created")]
+    MultisigMembersChanged,
+    #[msg("Proposal does not have enough approvals")]
+    NotEnoughApprovals,
+    #[msg("Pool has no fresh time-weighted price")]
+    PoolPriceUnavailable,
+    #[msg("Invalid TWAP window")]
+    InvalidTwapWindow,
+    #[msg("Free stays can't be reviewed")]
+    FreeStayNotReviewable,
+    #[msg("Proposal can still be executed")]
+    ProposalStillPending,
+    #[msg("Hotel collateral account does not match the hotel")]
+    HotelCollateralMismatch,
+    #[msg("COCO issued against the hotel is still outstanding")]
+    CollateralOutstanding,
+    #[msg("Hotel still has rooms or room types")]
+    HotelHasRooms,
+    #[msg("Room type still has rooms")]
+    RoomTypeInUse,
+    #[msg("Room still has a rental listing")]
+    RoomHasListing,
+    #[msg("Next calendar account does not match the stay")]
+    CalendarMismatch,
 }
\ No newline at end of file
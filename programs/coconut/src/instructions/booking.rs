@@ -0,0 +1,642 @@
+// File: instructions/booking.rs
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint};
+use crate::context::*;
+use crate::nft::{self, BurnNftAccounts};
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Confirms the renter's arrival and releases the escrowed rent to the verified hotel's owner,
+/// keeping any security deposit in escrow. Hotels should have guests sign this at the front
+/// desk before handing over the room; a booking the renter never confirms can be refunded as
+/// a no-show once the stay ends.
+pub fn check_in(ctx: Context<CheckIn>) -> Result<()> {
+    require!(ctx.accounts.hotel.is_verified, ErrorCode::HotelNotVerified);
+
+    let booking = &mut ctx.accounts.booking;
+    require!(booking.status == BookingStatus::Reserved, ErrorCode::InvalidBookingStatus);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= booking.check_in_timestamp(), ErrorCode::CheckInNotOpen);
+
+    let check_in_day_bytes = booking.check_in_day.to_le_bytes();
+    let booking_seeds: &[&[u8]] = &[
+        b"booking",
+        booking.room.as_ref(),
+        check_in_day_bytes.as_ref(),
+        &[booking.bump],
+    ];
+    let amount = booking.amount;
+
+    let hotel_income_bps = hotel_income_bps(
+        &ctx.accounts.hotel,
+        ctx.accounts.hotel_shares.as_deref().map(|shares| &**shares),
+        booking.payment_mint,
+    )?;
+    let hotel_income_vault = ctx.accounts.hotel_income_vault.as_ref().map(|vault| vault.to_account_info());
+
+    let payout = pay_out_rent(
+        amount,
+        booking.protocol_fee_bps,
+        booking.revenue_share_bps,
+        hotel_income_bps,
+        booking_seeds,
+        RentPayoutAccounts {
+            booking: &booking.to_account_info(),
+            escrow: &ctx.accounts.escrow.to_account_info(),
+            payment_mint: &ctx.accounts.payment_mint,
+            hotel_payout_account: &ctx.accounts.hotel_payout_account.to_account_info(),
+            treasury: &ctx.accounts.treasury.to_account_info(),
+            revenue_vault: &ctx.accounts.revenue_vault.to_account_info(),
+            hotel_income_vault: hotel_income_vault.as_ref(),
+            token_program: &ctx.accounts.token_program.to_account_info(),
+        },
+    )?;
+    ctx.accounts
+        .revenue_distributor
+        .rewards
+        .distribute(payout.revenue_share)
+        .ok_or(ErrorCode::Overflow)?;
+    if let Some(hotel_shares) = ctx.accounts.hotel_shares.as_deref_mut() {
+        hotel_shares.rewards.distribute(payout.hotel_income).ok_or(ErrorCode::Overflow)?;
+    }
+
+    booking.status = BookingStatus::CheckedIn;
+
+    emit!(BookingCheckedIn {
+        booking: booking.key(),
+        hotel: booking.hotel,
+        renter: booking.renter,
+        amount,
+        protocol_fee: payout.protocol_fee,
+        revenue_share: payout.revenue_share,
+        hotel_income: payout.hotel_income,
+    });
+
+    Ok(())
+}
+
+pub fn check_out(ctx: Context<CheckOut>) -> Result<()> {
+    let booking = &mut ctx.accounts.booking;
+    require!(booking.status == BookingStatus::CheckedIn, ErrorCode::InvalidBookingStatus);
+
+    let check_in_day_bytes = booking.check_in_day.to_le_bytes();
+    let booking_seeds: &[&[u8]] = &[
+        b"booking",
+        booking.room.as_ref(),
+        check_in_day_bytes.as_ref(),
+        &[booking.bump],
+    ];
+
+    burn_receipt(
+        &booking.key(),
+        ctx.bumps.receipt_mint,
+        BurnNftAccounts {
+            mint: &ctx.accounts.receipt_mint.to_account_info(),
+            holder_token_account: &ctx.accounts.receipt_token_account.to_account_info(),
+            rent_destination: &ctx.accounts.renter.to_account_info(),
+            token_program: &ctx.accounts.receipt_token_program.to_account_info(),
+        },
+    )?;
+
+    // Without a deposit the escrow was emptied at check-in, so return its rent to the renter.
+    // Otherwise it stays open until the deposit is released or a damage claim is resolved.
+    if booking.deposit_status == DepositStatus::Released {
+        token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::CloseAccount {
+                account: ctx.accounts.escrow.to_account_info(),
+                destination: ctx.accounts.renter.to_account_info(),
+                authority: booking.to_account_info(),
+            },
+            &[booking_seeds],
+        ))?;
+    }
+
+    booking.status = BookingStatus::CheckedOut;
+    booking.checked_out_at = Clock::get()?.unix_timestamp;
+
+    // A held deposit keeps the booking active so the hotel can't close while it's unsettled
+    if booking.deposit_status == DepositStatus::Released {
+        let hotel = &mut ctx.accounts.hotel;
+        hotel.active_bookings = hotel.active_bookings.saturating_sub(1);
+    }
+
+    emit!(BookingCheckedOut {
+        booking: booking.key(),
+        hotel: booking.hotel,
+        renter: booking.renter,
+    });
+
+    Ok(())
+}
+
+/// Cancels a booking before check-in, refunding the renter according to the cancellation
+/// policy captured at booking time and paying the retained amount to the hotel owner.
+pub fn cancel_booking(ctx: Context<CancelBooking>) -> Result<()> {
+    let booking = &ctx.accounts.booking;
+    require!(booking.status == BookingStatus::Reserved, ErrorCode::InvalidBookingStatus);
+
+    let now = Clock::get()?.unix_timestamp;
+    let check_in = booking.check_in_timestamp();
+    require!(now < check_in, ErrorCode::CancellationWindowClosed);
+
+    // The cancellation policy only applies to the rent; the deposit is always returned
+    let days_before_check_in = ((check_in - now) / SECONDS_PER_DAY) as u64;
+    let rent_refund = booking
+        .cancellation_policy
+        .refund_amount(booking.amount, days_before_check_in)
+        .ok_or(ErrorCode::Overflow)?;
+    let retained = booking.amount.checked_sub(rent_refund).ok_or(ErrorCode::Overflow)?;
+    let refund = rent_refund.checked_add(booking.security_deposit).ok_or(ErrorCode::Overflow)?;
+
+    // Free the nights for other renters
+    require!(
+        ctx.accounts.next_calendar.is_some() == RoomCalendar::spans_next_window(booking.check_in_day, booking.nights),
+        ErrorCode::CalendarMismatch
+    );
+    let window = booking.check_in_day / CALENDAR_WINDOW_DAYS;
+    for day in booking.check_in_day..booking.check_in_day + booking.nights as u32 {
+        match ctx.accounts.next_calendar.as_mut() {
+            Some(next_calendar) if day / CALENDAR_WINDOW_DAYS != window => next_calendar.release(day),
+            _ => ctx.accounts.calendar.release(day),
+        }
+    }
+
+    let check_in_day_bytes = booking.check_in_day.to_le_bytes();
+    let booking_seeds: &[&[u8]] = &[
+        b"booking",
+        booking.room.as_ref(),
+        check_in_day_bytes.as_ref(),
+        &[booking.bump],
+    ];
+
+    if refund > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.escrow.to_account_info(),
+                    mint: ctx.accounts.payment_mint.to_account_info(),
+                    to: ctx.accounts.renter_token_account.to_account_info(),
+                    authority: booking.to_account_info(),
+                },
+                &[booking_seeds],
+            ),
+            refund,
+            ctx.accounts.payment_mint.decimals,
+        )?;
+    }
+
+    let hotel_income_bps = hotel_income_bps(
+        &ctx.accounts.hotel,
+        ctx.accounts.hotel_shares.as_deref().map(|shares| &**shares),
+        booking.payment_mint,
+    )?;
+    let hotel_income_vault = ctx.accounts.hotel_income_vault.as_ref().map(|vault| vault.to_account_info());
+
+    let payout = pay_out_rent(
+        retained,
+        booking.protocol_fee_bps,
+        booking.revenue_share_bps,
+        hotel_income_bps,
+        booking_seeds,
+        RentPayoutAccounts {
+            booking: &booking.to_account_info(),
+            escrow: &ctx.accounts.escrow.to_account_info(),
+            payment_mint: &ctx.accounts.payment_mint,
+            hotel_payout_account: &ctx.accounts.hotel_payout_account.to_account_info(),
+            treasury: &ctx.accounts.treasury.to_account_info(),
+            revenue_vault: &ctx.accounts.revenue_vault.to_account_info(),
+            hotel_income_vault: hotel_income_vault.as_ref(),
+            token_program: &ctx.accounts.token_program.to_account_info(),
+        },
+    )?;
+    ctx.accounts
+        .revenue_distributor
+        .rewards
+        .distribute(payout.revenue_share)
+        .ok_or(ErrorCode::Overflow)?;
+    if let Some(hotel_shares) = ctx.accounts.hotel_shares.as_deref_mut() {
+        hotel_shares.rewards.distribute(payout.hotel_income).ok_or(ErrorCode::Overflow)?;
+    }
+
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::CloseAccount {
+            account: ctx.accounts.escrow.to_account_info(),
+            destination: ctx.accounts.renter.to_account_info(),
+            authority: booking.to_account_info(),
+        },
+        &[booking_seeds],
+    ))?;
+
+    burn_receipt(
+        &booking.key(),
+        ctx.bumps.receipt_mint,
+        BurnNftAccounts {
+            mint: &ctx.accounts.receipt_mint.to_account_info(),
+            holder_token_account: &ctx.accounts.receipt_token_account.to_account_info(),
+            rent_destination: &ctx.accounts.renter.to_account_info(),
+            token_program: &ctx.accounts.receipt_token_program.to_account_info(),
+        },
+    )?;
+
+    let hotel = &mut ctx.accounts.hotel;
+    hotel.active_bookings = hotel.active_bookings.saturating_sub(1);
+
+    emit!(BookingCancelled {
+        booking: booking.key(),
+        hotel: booking.hotel,
+        renter: booking.renter,
+        refund,
+        retained,
+        protocol_fee: payout.protocol_fee,
+        revenue_share: payout.revenue_share,
+        hotel_income: payout.hotel_income,
+    });
+
+    Ok(())
+}
+
+/// Refunds a booking the renter never checked in to once the stay is over, protecting
+/// renters against hotels that fail to provide the room.
+pub fn claim_no_show_refund(ctx: Context<ClaimNoShowRefund>) -> Result<()> {
+    let booking = &ctx.accounts.booking;
+    require!(booking.status == BookingStatus::Reserved, ErrorCode::InvalidBookingStatus);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= booking.check_out_timestamp(), ErrorCode::StayNotEnded);
+
+    let check_in_day_bytes = booking.check_in_day.to_le_bytes();
+    let booking_seeds: &[&[u8]] = &[
+        b"booking",
+        booking.room.as_ref(),
+        check_in_day_bytes.as_ref(),
+        &[booking.bump],
+    ];
+    let refund = ctx.accounts.escrow.amount;
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.escrow.to_account_info(),
+                mint: ctx.accounts.payment_mint.to_account_info(),
+                to: ctx.accounts.renter_token_account.to_account_info(),
+                authority: booking.to_account_info(),
+            },
+            &[booking_seeds],
+        ),
+        refund,
+        ctx.accounts.payment_mint.decimals,
+    )?;
+
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::CloseAccount {
+            account: ctx.accounts.escrow.to_account_info(),
+            destination: ctx.accounts.renter.to_account_info(),
+            authority: booking.to_account_info(),
+        },
+        &[booking_seeds],
+    ))?;
+
+    burn_receipt(
+        &booking.key(),
+        ctx.bumps.receipt_mint,
+        BurnNftAccounts {
+            mint: &ctx.accounts.receipt_mint.to_account_info(),
+            holder_token_account: &ctx.accounts.receipt_token_account.to_account_info(),
+            rent_destination: &ctx.accounts.renter.to_account_info(),
+            token_program: &ctx.accounts.receipt_token_program.to_account_info(),
+        },
+    )?;
+
+    let hotel = &mut ctx.accounts.hotel;
+    hotel.active_bookings = hotel.active_bookings.saturating_sub(1);
+
+    emit!(BookingRefunded {
+        booking: booking.key(),
+        hotel: booking.hotel,
+        renter: booking.renter,
+        amount: refund,
+    });
+
+    Ok(())
+}
+
+/// Returns the security deposit to the renter once the damage claim window has passed without
+/// a claim. Anyone can call this, so deposits can be returned automatically by a crank.
+pub fn release_deposit(ctx: Context<ReleaseDeposit>) -> Result<()> {
+    let booking = &mut ctx.accounts.booking;
+    require!(booking.status == BookingStatus::CheckedOut, ErrorCode::InvalidBookingStatus);
+    require!(booking.deposit_status == DepositStatus::Held, ErrorCode::InvalidDepositStatus);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(now > booking.damage_claim_deadline(), ErrorCode::DamageClaimWindowOpen);
+
+    let check_in_day_bytes = booking.check_in_day.to_le_bytes();
+    let booking_seeds: &[&[u8]] = &[
+        b"booking",
+        booking.room.as_ref(),
+        check_in_day_bytes.as_ref(),
+        &[booking.bump],
+    ];
+    let amount = booking.security_deposit;
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.escrow.to_account_info(),
+                mint: ctx.accounts.payment_mint.to_account_info(),
+                to: ctx.accounts.renter_token_account.to_account_info(),
+                authority: booking.to_account_info(),
+            },
+            &[booking_seeds],
+        ),
+        amount,
+        ctx.accounts.payment_mint.decimals,
+    )?;
+
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::CloseAccount {
+            account: ctx.accounts.escrow.to_account_info(),
+            destination: ctx.accounts.renter.to_account_info(),
+            authority: booking.to_account_info(),
+        },
+        &[booking_seeds],
+    ))?;
+
+    booking.deposit_status = DepositStatus::Released;
+
+    let hotel = &mut ctx.accounts.hotel;
+    hotel.active_bookings = hotel.active_bookings.saturating_sub(1);
+
+    emit!(DepositReleased {
+        booking: booking.key(),
+        renter: booking.renter,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Files a claim against the security deposit, freezing it until a protocol verifier rules on
+/// how it is split between the hotel and the renter.
+pub fn claim_damage(ctx: Context<ClaimDamage>, amount: u64) -> Result<()> {
+    let booking = &mut ctx.accounts.booking;
+    require!(booking.status == BookingStatus::CheckedOut, ErrorCode::InvalidBookingStatus);
+    require!(booking.deposit_status == DepositStatus::Held, ErrorCode::InvalidDepositStatus);
+    require!(amount > 0 && amount <= booking.security_deposit, ErrorCode::InvalidDamageClaim);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(now <= booking.damage_claim_deadline(), ErrorCode::DamageClaimWindowClosed);
+
+    booking.deposit_status = DepositStatus::Claimed;
+    booking.damage_claim = amount;
+
+    emit!(DamageClaimed {
+        booking: booking.key(),
+        hotel: booking.hotel,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Settles a damage claim by paying `hotel_amount` of the deposit to the hotel owner and
+/// returning the rest to the renter. The award can't exceed what the hotel claimed.
+pub fn resolve_damage_claim(ctx: Context<ResolveDamageClaim>, hotel_amount: u64) -> Result<()> {
+    let booking = &mut ctx.accounts.booking;
+    require!(booking.deposit_status == DepositStatus::Claimed, ErrorCode::InvalidDepositStatus);
+    require!(hotel_amount <= booking.damage_claim, ErrorCode::InvalidDamageClaim);
+
+    let check_in_day_bytes = booking.check_in_day.to_le_bytes();
+    let booking_seeds: &[&[u8]] = &[
+        b"booking",
+        booking.room.as_ref(),
+        check_in_day_bytes.as_ref(),
+        &[booking.bump],
+    ];
+    let renter_amount = booking.security_deposit - hotel_amount;
+
+    if hotel_amount > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.escrow.to_account_info(),
+                    mint: ctx.accounts.payment_mint.to_account_info(),
+                    to: ctx.accounts.hotel_payout_account.to_account_info(),
+                    authority: booking.to_account_info(),
+                },
+                &[booking_seeds],
+            ),
+            hotel_amount,
+            ctx.accounts.payment_mint.decimals,
+        )?;
+    }
+
+    if renter_amount > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.escrow.to_account_info(),
+                    mint: ctx.accounts.payment_mint.to_account_info(),
+                    to: ctx.accounts.renter_token_account.to_account_info(),
+                    authority: booking.to_account_info(),
+                },
+                &[booking_seeds],
+            ),
+            renter_amount,
+            ctx.accounts.payment_mint.decimals,
+        )?;
+    }
+
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::CloseAccount {
+            account: ctx.accounts.escrow.to_account_info(),
+            destination: ctx.accounts.renter.to_account_info(),
+            authority: booking.to_account_info(),
+        },
+        &[booking_seeds],
+    ))?;
+
+    booking.deposit_status = DepositStatus::Released;
+
+    let hotel = &mut ctx.accounts.hotel;
+    hotel.active_bookings = hotel.active_bookings.saturating_sub(1);
+
+    emit!(DamageClaimResolved {
+        booking: booking.key(),
+        hotel: booking.hotel,
+        verifier: ctx.accounts.verifier.key(),
+        hotel_amount,
+        renter_amount,
+    });
+
+    Ok(())
+}
+
+struct RentPayoutAccounts<'a, 'info> {
+    booking: &'a AccountInfo<'info>,
+    escrow: &'a AccountInfo<'info>,
+    payment_mint: &'a InterfaceAccount<'info, Mint>,
+    hotel_payout_account: &'a AccountInfo<'info>,
+    treasury: &'a AccountInfo<'info>,
+    revenue_vault: &'a AccountInfo<'info>,
+    hotel_income_vault: Option<&'a AccountInfo<'info>>,
+    token_program: &'a AccountInfo<'info>,
+}
+
+struct RentPayout {
+    protocol_fee: u64,
+    revenue_share: u64,
+    hotel_income: u64,
+}
+
+/// Share of the hotel's payout owed to its shareholders, which only applies to rent paid in
+/// the shares' income mint.
+fn hotel_income_bps(hotel: &Hotel, hotel_shares: Option<&HotelShares>, payment_mint: Pubkey) -> Result<u16> {
+    require!(hotel_shares.is_some() == hotel.has_shares, ErrorCode::HotelSharesMismatch);
+
+    Ok(hotel_shares
+        .filter(|shares| shares.income_mint == payment_mint)
+        .map_or(0, |shares| shares.income_share_bps))
+}
+
+/// Pays `amount` of escrowed rent out to the hotel owner after taking the protocol fee for the
+/// treasury and the staker revenue share for the distributor vault. The hotel's shareholders
+/// then receive `hotel_income_bps` of what remains.
+fn pay_out_rent<'info>(
+    amount: u64,
+    protocol_fee_bps: u16,
+    revenue_share_bps: u16,
+    hotel_income_bps: u16,
+    booking_seeds: &[&[u8]],
+    accounts: RentPayoutAccounts<'_, 'info>,
+) -> Result<RentPayout> {
+    let protocol_fee = bps_of(amount, protocol_fee_bps).ok_or(ErrorCode::Overflow)?;
+    let revenue_share = bps_of(amount, revenue_share_bps).ok_or(ErrorCode::Overflow)?;
+    let hotel_payout = amount
+        .checked_sub(protocol_fee)
+        .and_then(|remaining| remaining.checked_sub(revenue_share))
+        .ok_or(ErrorCode::Overflow)?;
+    let hotel_income = bps_of(hotel_payout, hotel_income_bps).ok_or(ErrorCode::Overflow)?;
+    let hotel_amount = hotel_payout - hotel_income;
+
+    let mut transfers = vec![
+        (accounts.hotel_payout_account, hotel_amount),
+        (accounts.treasury, protocol_fee),
+        (accounts.revenue_vault, revenue_share),
+    ];
+    if hotel_income > 0 {
+        let hotel_income_vault = accounts.hotel_income_vault.ok_or(ErrorCode::HotelSharesMismatch)?;
+        transfers.push((hotel_income_vault, hotel_income));
+    }
+
+    for (destination, transfer_amount) in transfers {
+        if transfer_amount == 0 {
+            continue;
+        }
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                accounts.token_program.clone(),
+                token_interface::TransferChecked {
+                    from: accounts.escrow.clone(),
+                    mint: accounts.payment_mint.to_account_info(),
+                    to: destination.clone(),
+                    authority: accounts.booking.clone(),
+                },
+                &[booking_seeds],
+            ),
+            transfer_amount,
+            accounts.payment_mint.decimals,
+        )?;
+    }
+
+    Ok(RentPayout {
+        protocol_fee,
+        revenue_share,
+        hotel_income,
+    })
+}
+
+fn burn_receipt<'info>(booking: &Pubkey, bump: u8, accounts: BurnNftAccounts<'_, 'info>) -> Result<()> {
+    let receipt_seeds: &[&[u8]] = &[b"booking_receipt", booking.as_ref(), &[bump]];
+    nft::burn_nft(accounts, receipt_seeds)
+}
+
+#[event]
+pub struct BookingCheckedIn {
+    pub booking: Pubkey,
+    pub hotel: Pubkey,
+    pub renter: Pubkey,
+    /// Rent released from escrow; the hotel receives
+    /// `amount - protocol_fee - revenue_share - hotel_income`
+    pub amount: u64,
+    pub protocol_fee: u64,
+    pub revenue_share: u64,
+    /// Part of the hotel's payout distributed to its shareholders
+    pub hotel_income: u64,
+}
+
+#[event]
+pub struct BookingCheckedOut {
+    pub booking: Pubkey,
+    pub hotel: Pubkey,
+    pub renter: Pubkey,
+}
+
+#[event]
+pub struct BookingCancelled {
+    pub booking: Pubkey,
+    pub hotel: Pubkey,
+    pub renter: Pubkey,
+    pub refund: u64,
+    /// Rent kept under the cancellation policy; the hotel
+    /// receives `retained - protocol_fee - revenue_share - hotel_income`
+    pub retained: u64,
+    pub protocol_fee: u64,
+    pub revenue_share: u64,
+    /// Part of the hotel's payout distributed to its shareholders
+    pub hotel_income: u64,
+}
+
+#[event]
+pub struct BookingRefunded {
+    pub booking: Pubkey,
+    pub hotel: Pubkey,
+    pub renter: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DepositReleased {
+    pub booking: Pubkey,
+    pub renter: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DamageClaimed {
+    pub booking: Pubkey,
+    pub hotel: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DamageClaimResolved {
+    pub booking: Pubkey,
+    pub hotel: Pubkey,
+    pub verifier: Pubkey,
+    pub hotel_amount: u64,
+    pub renter_amount: u64,
+}
\ No newline at end of file
@@ -2,42 +2,300 @@
 
 use anchor_lang::prelude::*;
 use crate::context::*;
+use crate::nft::{self, BurnNftAccounts, MintNftAccounts, NftMetadata};
 use crate::state::*;
 use crate::errors::ErrorCode;
 
-pub fn initialize_hotel(ctx: Context<InitializeHotel>, name: String, room_count: u16) -> Result<()> {
+const VERIFICATION_SYMBOL: &str = "COCOV";
+
+pub fn initialize_hotel_registry(ctx: Context<InitializeHotelRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.hotel_registry;
+    registry.hotel_count = 0;
+    registry.bump = ctx.bumps.hotel_registry;
+
+    Ok(())
+}
+
+pub fn initialize_hotel(ctx: Context<InitializeHotel>, hotel_id: u64, name: String, room_count: u16) -> Result<()> {
     require!(room_count > 0, ErrorCode::InvalidRoomCount);
+    require!(!name.is_empty() && name.len() <= MAX_HOTEL_NAME_LEN, ErrorCode::InvalidHotelName);
+
+    let registry = &mut ctx.accounts.hotel_registry;
+    let registry_index = registry.hotel_count;
+    registry.hotel_count = registry.hotel_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
 
     let hotel = &mut ctx.accounts.hotel;
     hotel.owner = ctx.accounts.owner.key();
+    hotel.creator = ctx.accounts.owner.key();
+    hotel.hotel_id = hotel_id;
     hotel.name = name;
     hotel.room_count = room_count;
     hotel.is_verified = false;
+    hotel.registry_index = registry_index;
+    hotel.pending_owner = None;
+    hotel.listing_count = 0;
+    hotel.room_type_count = 0;
+    hotel.active_bookings = 0;
+    hotel.transferable_receipts = false;
+    hotel.resale_royalty_bps = 0;
+    hotel.has_shares = false;
+    hotel.rating_count = 0;
+    hotel.rating_sum = 0;
+    hotel.max_room_number = 0;
+    hotel.has_collateral = false;
+    hotel.open_rooms = 0;
+    hotel.open_room_types = 0;
+    hotel.bump = ctx.bumps.hotel;
+
+    let entry = &mut ctx.accounts.registry_entry;
+    entry.hotel = hotel.key();
+    entry.bump = ctx.bumps.registry_entry;
+
+    emit!(HotelInitialized {
+        hotel: hotel.key(),
+        owner: hotel.owner,
+        hotel_id,
+        registry_index,
+        name: hotel.name.clone(),
+        room_count,
+    });
+
+    Ok(())
+}
+
+pub fn update_hotel(
+    ctx: Context<UpdateHotel>,
+    name: Option<String>,
+    room_count: Option<u16>,
+    transferable_receipts: Option<bool>,
+    resale_royalty_bps: Option<u16>,
+) -> Result<()> {
+    let hotel = &mut ctx.accounts.hotel;
+
+    if let Some(name) = name {
+        require!(!name.is_empty() && name.len() <= MAX_HOTEL_NAME_LEN, ErrorCode::InvalidHotelName);
+        hotel.name = name;
+    }
+
+    if let Some(room_count) = room_count {
+        require!(room_count > 0, ErrorCode::InvalidRoomCount);
+        // Existing rooms can't be orphaned beyond the hotel's room count
+        require!(room_count >= hotel.max_room_number, ErrorCode::InvalidRoomCount);
+        hotel.room_count = room_count;
+    }
+
+    // Only affects receipts minted for future bookings
+    if let Some(transferable_receipts) = transferable_receipts {
+        hotel.transferable_receipts = transferable_receipts;
+    }
+
+    if let Some(resale_royalty_bps) = resale_royalty_bps {
+        require!(resale_royalty_bps as u64 <= BASIS_POINTS, ErrorCode::InvalidRoyalty);
+        hotel.resale_royalty_bps = resale_royalty_bps;
+    }
+
+    emit!(HotelUpdated {
+        hotel: hotel.key(),
+        name: hotel.name.clone(),
+        room_count: hotel.room_count,
+        transferable_receipts: hotel.transferable_receipts,
+        resale_royalty_bps: hotel.resale_royalty_bps,
+    });
+
+    Ok(())
+}
+
+pub fn propose_hotel_owner(ctx: Context<ProposeHotelOwner>, new_owner: Pubkey) -> Result<()> {
+    let hotel = &mut ctx.accounts.hotel;
+    hotel.pending_owner = Some(new_owner);
+
+    emit!(HotelOwnershipProposed {
+        hotel: hotel.key(),
+        owner: hotel.owner,
+        pending_owner: new_owner,
+    });
+
+    Ok(())
+}
+
+pub fn accept_hotel_owner(ctx: Context<AcceptHotelOwner>) -> Result<()> {
+    let hotel = &mut ctx.accounts.hotel;
+    // The verification token is soulbound to the current owner, so it must be revoked first
+    require!(!hotel.is_verified, ErrorCode::HotelStillVerified);
+
+    let previous_owner = hotel.owner;
+    hotel.owner = ctx.accounts.new_owner.key();
+    hotel.pending_owner = None;
+
+    emit!(HotelOwnershipTransferred {
+        hotel: hotel.key(),
+        previous_owner,
+        new_owner: hotel.owner,
+    });
+
+    Ok(())
+}
+
+pub fn close_hotel(ctx: Context<CloseHotel>) -> Result<()> {
+    let hotel = &ctx.accounts.hotel;
+    require!(!hotel.is_verified, ErrorCode::HotelStillVerified);
+    require!(hotel.listing_count == 0, ErrorCode::HotelHasActiveListings);
+    require!(hotel.active_bookings == 0, ErrorCode::HotelHasActiveBookings);
+    require!(!hotel.has_shares, ErrorCode::HotelHasShares);
+    require!(hotel.open_rooms == 0 && hotel.open_room_types == 0, ErrorCode::HotelHasRooms);
+
+    // The collateral record is closed with the hotel, once everything issued against it is repaid
+    let collateral = ctx.accounts.hotel_collateral.as_ref();
+    require!(collateral.is_some() == hotel.has_collateral, ErrorCode::HotelCollateralMismatch);
+    if let Some(collateral) = collateral {
+        require!(collateral.issued_amount == 0, ErrorCode::CollateralOutstanding);
+    }
+
+    emit!(HotelClosed {
+        hotel: hotel.key(),
+        owner: hotel.owner,
+        registry_index: hotel.registry_index,
+    });
 
     Ok(())
 }
 
 pub fn verify_hotel(ctx: Context<VerifyHotel>) -> Result<()> {
     let hotel = &mut ctx.accounts.hotel;
-    
-    // In a real-world scenario, you'd want to check if the authority is actually allowed to verify hotels
-    // For simplicity, we're not implementing that check here
-    
+    require!(!hotel.is_verified, ErrorCode::HotelAlreadyVerified);
+
     hotel.is_verified = true;
 
-    // Here you would implement the logic to mint a Soulbound NFT to the hotel owner
-    // For brevity, we're not implementing the full NFT minting logic
+    // Mint a non-transferable verification token to the hotel owner
+    let verified_at = Clock::get()?.unix_timestamp;
+    let hotel_key = hotel.key();
+    let mint_seeds: &[&[u8]] = &[
+        b"verification_mint",
+        hotel_key.as_ref(),
+        &[ctx.bumps.verification_mint],
+    ];
+
+    nft::mint_nft(
+        MintNftAccounts {
+            payer: &ctx.accounts.verifier.to_account_info(),
+            mint: &ctx.accounts.verification_mint.to_account_info(),
+            recipient: &ctx.accounts.owner.to_account_info(),
+            recipient_token_account: &ctx.accounts.owner_token_account.to_account_info(),
+            token_program: &ctx.accounts.token_program.to_account_info(),
+            associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+        },
+        mint_seeds,
+        NftMetadata {
+            name: hotel.name.clone(),
+            symbol: VERIFICATION_SYMBOL.to_string(),
+            uri: String::new(),
+            additional_metadata: vec![
+                ("hotel".to_string(), hotel_key.to_string()),
+                ("verified_at".to_string(), verified_at.to_string()),
+            ],
+        },
+        true,
+    )?;
 
     emit!(HotelVerified {
-        hotel: hotel.key(),
+        hotel: hotel_key,
         owner: hotel.owner,
+        verifier: ctx.accounts.verifier.key(),
+        verification_mint: ctx.accounts.verification_mint.key(),
+        verified_at,
     });
 
     Ok(())
 }
 
+pub fn unverify_hotel(ctx: Context<UnverifyHotel>) -> Result<()> {
+    let hotel = &mut ctx.accounts.hotel;
+    require!(hotel.is_verified, ErrorCode::HotelNotVerified);
+
+    hotel.is_verified = false;
+
+    // Burn the owner's verification token and reclaim the mint
+    let hotel_key = hotel.key();
+    let mint_seeds: &[&[u8]] = &[
+        b"verification_mint",
+        hotel_key.as_ref(),
+        &[ctx.bumps.verification_mint],
+    ];
+
+    nft::burn_nft(
+        BurnNftAccounts {
+            mint: &ctx.accounts.verification_mint.to_account_info(),
+            holder_token_account: &ctx.accounts.owner_token_account.to_account_info(),
+            rent_destination: &ctx.accounts.verifier.to_account_info(),
+            token_program: &ctx.accounts.token_program.to_account_info(),
+        },
+        mint_seeds,
+    )?;
+
+    emit!(HotelUnverified {
+        hotel: hotel_key,
+        owner: hotel.owner,
+        verifier: ctx.accounts.verifier.key(),
+        verification_mint: ctx.accounts.verification_mint.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct HotelInitialized {
+    pub hotel: Pubkey,
+    pub owner: Pubkey,
+    pub hotel_id: u64,
+    pub registry_index: u64,
+    pub name: String,
+    pub room_count: u16,
+}
+
+#[event]
+pub struct HotelUpdated {
+    pub hotel: Pubkey,
+    pub name: String,
+    pub room_count: u16,
+    pub transferable_receipts: bool,
+    pub resale_royalty_bps: u16,
+}
+
+#[event]
+pub struct HotelOwnershipProposed {
+    pub hotel: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+#[event]
+pub struct HotelOwnershipTransferred {
+    pub hotel: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[event]
+pub struct HotelClosed {
+    pub hotel: Pubkey,
+    pub owner: Pubkey,
+    pub registry_index: u64,
+}
+
 #[event]
 pub struct HotelVerified {
     pub hotel: Pubkey,
     pub owner: Pubkey,
+    pub verifier: Pubkey,
+    pub verification_mint: Pubkey,
+    pub verified_at: i64,
+}
+
+#[event]
+pub struct HotelUnverified {
+    pub hotel: Pubkey,
+    pub owner: Pubkey,
+    pub verifier: Pubkey,
+    pub verification_mint: Pubkey,
 }
\ No newline at end of file
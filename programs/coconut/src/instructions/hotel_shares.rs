@@ -0,0 +1,248 @@
+// File: instructions/hotel_shares.rs
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use crate::context::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Creates the hotel's fractional share mint. The income share is fixed here so the owner
+/// can't cut shareholders out of the rental income after selling shares.
+pub fn create_hotel_shares(
+    ctx: Context<CreateHotelShares>,
+    _decimals: u8,
+    max_supply: u64,
+    income_share_bps: u16,
+) -> Result<()> {
+    let hotel = &mut ctx.accounts.hotel;
+    require!(hotel.is_verified, ErrorCode::HotelNotVerified);
+    require!(max_supply > 0, ErrorCode::InvalidShareSupply);
+    require!(income_share_bps as u64 <= BASIS_POINTS, ErrorCode::InvalidIncomeShare);
+
+    hotel.has_shares = true;
+
+    let hotel_shares = &mut ctx.accounts.hotel_shares;
+    hotel_shares.hotel = hotel.key();
+    hotel_shares.share_mint = ctx.accounts.share_mint.key();
+    hotel_shares.income_mint = ctx.accounts.income_mint.key();
+    hotel_shares.max_supply = max_supply;
+    hotel_shares.issued_supply = 0;
+    hotel_shares.income_share_bps = income_share_bps;
+    hotel_shares.rewards = RewardAccumulator::default();
+    hotel_shares.bump = ctx.bumps.hotel_shares;
+
+    emit!(HotelSharesCreated {
+        hotel: hotel_shares.hotel,
+        share_mint: hotel_shares.share_mint,
+        income_mint: hotel_shares.income_mint,
+        max_supply,
+        income_share_bps,
+    });
+
+    Ok(())
+}
+
+pub fn issue_hotel_shares(ctx: Context<IssueHotelShares>, amount: u64) -> Result<()> {
+    let hotel = &ctx.accounts.hotel;
+    require!(hotel.is_verified, ErrorCode::HotelNotVerified);
+
+    let hotel_shares = &mut ctx.accounts.hotel_shares;
+    let issued_supply = hotel_shares.issued_supply.checked_add(amount).ok_or(ErrorCode::Overflow)?;
+    require!(issued_supply <= hotel_shares.max_supply, ErrorCode::ShareSupplyExceeded);
+    hotel_shares.issued_supply = issued_supply;
+
+    let hotel_key = hotel.key();
+    let hotel_shares_seeds: &[&[u8]] = &[b"hotel_shares", hotel_key.as_ref(), &[hotel_shares.bump]];
+
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::MintTo {
+                mint: ctx.accounts.share_mint.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: hotel_shares.to_account_info(),
+            },
+            &[hotel_shares_seeds],
+        ),
+        amount,
+    )?;
+
+    emit!(HotelSharesIssued {
+        hotel: hotel_key,
+        recipient: ctx.accounts.recipient_token_account.key(),
+        amount,
+        issued_supply,
+    });
+
+    Ok(())
+}
+
+pub fn deposit_hotel_shares(ctx: Context<DepositHotelShares>, amount: u64) -> Result<()> {
+    let hotel_shares = &mut ctx.accounts.hotel_shares;
+    let position = &mut ctx.accounts.position;
+
+    if position.owner == Pubkey::default() {
+        position.owner = ctx.accounts.investor.key();
+        position.hotel_shares = hotel_shares.key();
+        position.bump = ctx.bumps.position;
+    }
+
+    // Bank income earned by the old position before the new shares start earning
+    hotel_shares
+        .rewards
+        .settle(position.amount, position.reward_debt, &mut position.pending_rewards)
+        .ok_or(ErrorCode::Overflow)?;
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.investor_token_account.to_account_info(),
+                mint: ctx.accounts.share_mint.to_account_info(),
+                to: ctx.accounts.share_vault.to_account_info(),
+                authority: ctx.accounts.investor.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.share_mint.decimals,
+    )?;
+
+    position.amount = position.amount.checked_add(amount).ok_or(ErrorCode::Overflow)?;
+    position.reward_debt = hotel_shares.rewards.accrued(position.amount).ok_or(ErrorCode::Overflow)?;
+    hotel_shares.rewards.total_deposited = hotel_shares
+        .rewards
+        .total_deposited
+        .checked_add(amount)
+        .ok_or(ErrorCode::Overflow)?;
+
+    emit!(HotelSharesDeposited {
+        hotel: hotel_shares.hotel,
+        investor: position.owner,
+        amount,
+    });
+
+    Ok(())
+}
+
+pub fn withdraw_hotel_shares(ctx: Context<WithdrawHotelShares>, amount: u64) -> Result<()> {
+    let hotel_shares = &mut ctx.accounts.hotel_shares;
+    let position = &mut ctx.accounts.position;
+    require!(position.amount >= amount, ErrorCode::InsufficientShares);
+
+    // Bank income earned so far; it stays claimable after withdrawing
+    hotel_shares
+        .rewards
+        .settle(position.amount, position.reward_debt, &mut position.pending_rewards)
+        .ok_or(ErrorCode::Overflow)?;
+
+    let hotel_shares_seeds: &[&[u8]] = &[b"hotel_shares", hotel_shares.hotel.as_ref(), &[hotel_shares.bump]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.share_vault.to_account_info(),
+                mint: ctx.accounts.share_mint.to_account_info(),
+                to: ctx.accounts.investor_token_account.to_account_info(),
+                authority: hotel_shares.to_account_info(),
+            },
+            &[hotel_shares_seeds],
+        ),
+        amount,
+        ctx.accounts.share_mint.decimals,
+    )?;
+
+    position.amount -= amount;
+    position.reward_debt = hotel_shares.rewards.accrued(position.amount).ok_or(ErrorCode::Overflow)?;
+    hotel_shares.rewards.total_deposited = hotel_shares
+        .rewards
+        .total_deposited
+        .checked_sub(amount)
+        .ok_or(ErrorCode::Overflow)?;
+
+    emit!(HotelSharesWithdrawn {
+        hotel: hotel_shares.hotel,
+        investor: position.owner,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Pays out the investor's share of the hotel's rental income accrued since their last claim.
+pub fn claim_hotel_income(ctx: Context<ClaimHotelIncome>) -> Result<()> {
+    let hotel_shares = &ctx.accounts.hotel_shares;
+    let position = &mut ctx.accounts.position;
+    hotel_shares
+        .rewards
+        .settle(position.amount, position.reward_debt, &mut position.pending_rewards)
+        .ok_or(ErrorCode::Overflow)?;
+    position.reward_debt = hotel_shares.rewards.accrued(position.amount).ok_or(ErrorCode::Overflow)?;
+
+    let amount = position.pending_rewards;
+    require!(amount > 0, ErrorCode::NothingToClaim);
+    position.pending_rewards = 0;
+
+    let hotel_shares_seeds: &[&[u8]] = &[b"hotel_shares", hotel_shares.hotel.as_ref(), &[hotel_shares.bump]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.hotel_income_vault.to_account_info(),
+                mint: ctx.accounts.income_mint.to_account_info(),
+                to: ctx.accounts.investor_income_account.to_account_info(),
+                authority: hotel_shares.to_account_info(),
+            },
+            &[hotel_shares_seeds],
+        ),
+        amount,
+        ctx.accounts.income_mint.decimals,
+    )?;
+
+    emit!(HotelIncomeClaimed {
+        hotel: hotel_shares.hotel,
+        investor: position.owner,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct HotelSharesCreated {
+    pub hotel: Pubkey,
+    pub share_mint: Pubkey,
+    pub income_mint: Pubkey,
+    pub max_supply: u64,
+    pub income_share_bps: u16,
+}
+
+#[event]
+pub struct HotelSharesIssued {
+    pub hotel: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub issued_supply: u64,
+}
+
+#[event]
+pub struct HotelSharesDeposited {
+    pub hotel: Pubkey,
+    pub investor: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct HotelSharesWithdrawn {
+    pub hotel: Pubkey,
+    pub investor: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct HotelIncomeClaimed {
+    pub hotel: Pubkey,
+    pub investor: Pubkey,
+    pub amount: u64,
+}
\ No newline at end of file
@@ -6,50 +6,71 @@ use crate::context::*;
 use crate::state::*;
 use crate::errors::ErrorCode;
 
-pub fn create_liquidity_pool(ctx: Context<CreateLiquidityPool>, initial_liquidity: u64) -> Result<()> {
+pub fn create_liquidity_pool(ctx: Context<CreateLiquidityPool>, initial_liquidity: u64, twap_window: i64) -> Result<()> {
+    require!(
+        (MIN_TWAP_WINDOW..=MAX_TWAP_WINDOW).contains(&twap_window),
+        ErrorCode::InvalidTwapWindow
+    );
+
+    let now = Clock::get()?.unix_timestamp;
     let pool = &mut ctx.accounts.liquidity_pool;
     pool.total_liquidity = initial_liquidity;
     pool.coco_token_reserve = 0;
     pool.usdc_reserve = 0;
+    pool.coco_mint = ctx.accounts.coco_mint.key();
+    pool.usdc_mint = ctx.accounts.usdc_mint.key();
+    pool.price_cumulative = 0;
+    pool.last_update_timestamp = now;
+    pool.previous_observation = PriceObservation { price_cumulative: 0, timestamp: now };
+    pool.latest_observation = pool.previous_observation;
+    pool.twap_window = twap_window;
+    pool.authority = ctx.accounts.creator.key();
+    pool.pending_authority = None;
+    pool.bump = ctx.bumps.liquidity_pool;
 
     emit!(LiquidityPoolCreated {
         pool: pool.key(),
         creator: ctx.accounts.creator.key(),
         initial_liquidity,
+        twap_window,
     });
 
     Ok(())
 }
 
 pub fn add_liquidity(ctx: Context<AddLiquidity>, coco_amount: u64, usdc_amount: u64) -> Result<()> {
-    let pool = &mut ctx.accounts.liquidity_pool;
-
     // Transfer COCO tokens to the pool
-    token_interface::transfer(
+    token_interface::transfer_checked(
         CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token_interface::Transfer {
+            ctx.accounts.coco_token_program.to_account_info(),
+            token_interface::TransferChecked {
                 from: ctx.accounts.coco_token_account.to_account_info(),
-                to: pool.to_account_info(),
+                mint: ctx.accounts.coco_mint.to_account_info(),
+                to: ctx.accounts.coco_vault.to_account_info(),
                 authority: ctx.accounts.user.to_account_info(),
             },
         ),
         coco_amount,
+        ctx.accounts.coco_mint.decimals,
     )?;
 
     // Transfer USDC to the pool
-    token_interface::transfer(
+    token_interface::transfer_checked(
         CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token_interface::Transfer {
+            ctx.accounts.usdc_token_program.to_account_info(),
+            token_interface::TransferChecked {
                 from: ctx.accounts.usdc_token_account.to_account_info(),
-                to: pool.to_account_info(),
+                mint: ctx.accounts.usdc_mint.to_account_info(),
+                to: ctx.accounts.usdc_vault.to_account_info(),
                 authority: ctx.accounts.user.to_account_info(),
             },
         ),
         usdc_amount,
+        ctx.accounts.usdc_mint.decimals,
     )?;
 
+    let pool = &mut ctx.accounts.liquidity_pool;
+    pool.update_price(Clock::get()?.unix_timestamp);
     pool.coco_token_reserve = pool.coco_token_reserve.checked_add(coco_amount).ok_or(ErrorCode::Overflow)?;
     pool.usdc_reserve = pool.usdc_reserve.checked_add(usdc_amount).ok_or(ErrorCode::Overflow)?;
     pool.total_liquidity = pool.total_liquidity.checked_add(coco_amount.min(usdc_amount)).ok_or(ErrorCode::Overflow)?;
@@ -65,9 +86,11 @@ pub fn add_liquidity(ctx: Context<AddLiquidity>, coco_amount: u64, usdc_amount:
 }
 
 pub fn swap_tokens(ctx: Context<SwapTokens>, amount_in: u64, min_amount_out: u64) -> Result<()> {
-    let pool = &mut ctx.accounts.liquidity_pool;
-    
-    let (reserve_in, reserve_out) = if ctx.accounts.token_account_in.mint == pool.coco_token_reserve {
+    let accounts = &ctx.accounts;
+    let pool = &accounts.liquidity_pool;
+    let coco_in = accounts.token_account_in.mint == pool.coco_mint;
+
+    let (reserve_in, reserve_out) = if coco_in {
         (pool.coco_token_reserve, pool.usdc_reserve)
     } else {
         (pool.usdc_reserve, pool.coco_token_reserve)
@@ -77,34 +100,68 @@ pub fn swap_tokens(ctx: Context<SwapTokens>, amount_in: u64, min_amount_out: u64
     let amount_out = (amount_in as u128 * reserve_out as u128 / (reserve_in as u128 + amount_in as u128)) as u64;
     require!(amount_out >= min_amount_out, ErrorCode::SlippageExceeded);
 
+    let (mint_in, vault_in, program_in, mint_out, vault_out, program_out) = if coco_in {
+        (
+            &accounts.coco_mint,
+            &accounts.coco_vault,
+            &accounts.coco_token_program,
+            &accounts.usdc_mint,
+            &accounts.usdc_vault,
+            &accounts.usdc_token_program,
+        )
+    } else {
+        (
+            &accounts.usdc_mint,
+            &accounts.usdc_vault,
+            &accounts.usdc_token_program,
+            &accounts.coco_mint,
+            &accounts.coco_vault,
+            &accounts.coco_token_program,
+        )
+    };
+
     // Transfer input tokens to the pool
-    token_interface::transfer(
+    token_interface::transfer_checked(
         CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token_interface::Transfer {
-                from: ctx.accounts.token_account_in.to_account_info(),
-                to: pool.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
+            program_in.to_account_info(),
+            token_interface::TransferChecked {
+                from: accounts.token_account_in.to_account_info(),
+                mint: mint_in.to_account_info(),
+                to: vault_in.to_account_info(),
+                authority: accounts.user.to_account_info(),
             },
         ),
         amount_in,
+        mint_in.decimals,
     )?;
 
     // Transfer output tokens from the pool to the user
-    token_interface::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token_interface::Transfer {
-                from: pool.to_account_info(),
-                to: ctx.accounts.token_account_out.to_account_info(),
+    let pool_seeds: &[&[u8]] = &[
+        b"liquidity_pool",
+        pool.coco_mint.as_ref(),
+        pool.usdc_mint.as_ref(),
+        &[pool.bump],
+    ];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            program_out.to_account_info(),
+            token_interface::TransferChecked {
+                from: vault_out.to_account_info(),
+                mint: mint_out.to_account_info(),
+                to: accounts.token_account_out.to_account_info(),
                 authority: pool.to_account_info(),
             },
+            &[pool_seeds],
         ),
         amount_out,
+        mint_out.decimals,
     )?;
 
     // Update pool reserves
-    if ctx.accounts.token_account_in.mint == pool.coco_token_reserve {
+    let pool = &mut ctx.accounts.liquidity_pool;
+    pool.update_price(Clock::get()?.unix_timestamp);
+    if coco_in {
         pool.coco_token_reserve = pool.coco_token_reserve.checked_add(amount_in).ok_or(ErrorCode::Overflow)?;
         pool.usdc_reserve = pool.usdc_reserve.checked_sub(amount_out).ok_or(ErrorCode::InsufficientFunds)?;
     } else {
@@ -122,11 +179,76 @@ pub fn swap_tokens(ctx: Context<SwapTokens>, amount_in: u64, min_amount_out: u64
     Ok(())
 }
 
+/// Accrues the pool price and rolls its TWAP observations forward. Permissionless; keepers
+/// call it at least every `twap_window` so booking quotes don't go stale between trades.
+pub fn sync_pool_price(ctx: Context<SyncPoolPrice>) -> Result<()> {
+    let pool = &mut ctx.accounts.liquidity_pool;
+    let now = Clock::get()?.unix_timestamp;
+    pool.update_price(now);
+
+    emit!(PoolPriceSynced {
+        pool: pool.key(),
+        price_cumulative: pool.price_cumulative,
+        twap_price: pool.twap_price(now),
+    });
+
+    Ok(())
+}
+
+pub fn set_pool_twap_window(ctx: Context<UpdateLiquidityPool>, twap_window: i64) -> Result<()> {
+    require!(
+        (MIN_TWAP_WINDOW..=MAX_TWAP_WINDOW).contains(&twap_window),
+        ErrorCode::InvalidTwapWindow
+    );
+
+    let pool = &mut ctx.accounts.liquidity_pool;
+    pool.update_price(Clock::get()?.unix_timestamp);
+    pool.twap_window = twap_window;
+
+    emit!(PoolTwapWindowUpdated {
+        pool: pool.key(),
+        twap_window,
+    });
+
+    Ok(())
+}
+
+/// Proposes handing control of the pool to `new_authority`, e.g. a multisig signer. It takes
+/// effect once the new authority accepts.
+pub fn propose_pool_authority(ctx: Context<UpdateLiquidityPool>, new_authority: Pubkey) -> Result<()> {
+    let pool = &mut ctx.accounts.liquidity_pool;
+    pool.pending_authority = Some(new_authority);
+
+    emit!(PoolAuthorityProposed {
+        pool: pool.key(),
+        authority: pool.authority,
+        pending_authority: new_authority,
+    });
+
+    Ok(())
+}
+
+pub fn accept_pool_authority(ctx: Context<AcceptPoolAuthority>) -> Result<()> {
+    let pool = &mut ctx.accounts.liquidity_pool;
+    let previous_authority = pool.authority;
+    pool.authority = ctx.accounts.new_authority.key();
+    pool.pending_authority = None;
+
+    emit!(PoolAuthorityUpdated {
+        pool: pool.key(),
+        previous_authority,
+        new_authority: pool.authority,
+    });
+
+    Ok(())
+}
+
 #[event]
 pub struct LiquidityPoolCreated {
     pub pool: Pubkey,
     pub creator: Pubkey,
     pub initial_liquidity: u64,
+    pub twap_window: i64,
 }
 
 #[event]
@@ -143,4 +265,32 @@ pub struct TokensSwapped {
     pub user: Pubkey,
     pub amount_in: u64,
     pub amount_out: u64,
+}
+
+#[event]
+pub struct PoolPriceSynced {
+    pub pool: Pubkey,
+    pub price_cumulative: u128,
+    /// `None` while the pool has no fresh TWAP
+    pub twap_price: Option<u128>,
+}
+
+#[event]
+pub struct PoolTwapWindowUpdated {
+    pub pool: Pubkey,
+    pub twap_window: i64,
+}
+
+#[event]
+pub struct PoolAuthorityProposed {
+    pub pool: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct PoolAuthorityUpdated {
+    pub pool: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
 }
\ No newline at end of file
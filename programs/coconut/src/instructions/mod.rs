@@ -6,10 +6,30 @@ pub mod liquidity_pool;
 pub mod staking;
 pub mod rental;
 pub mod confidential_transfer;
+pub mod protocol_config;
+pub mod room;
+pub mod booking;
+pub mod pricing;
+pub mod resale;
+pub mod review;
+pub mod revenue;
+pub mod hotel_shares;
+pub mod vesting;
+pub mod multisig;
 
 pub use hotel::*;
 pub use token::*;
 pub use liquidity_pool::*;
 pub use staking::*;
 pub use rental::*;
-pub use confidential_transfer::*;
\ No newline at end of file
+pub use confidential_transfer::*;
+pub use protocol_config::*;
+pub use room::*;
+pub use booking::*;
+pub use pricing::*;
+pub use resale::*;
+pub use review::*;
+pub use revenue::*;
+pub use hotel_shares::*;
+pub use vesting::*;
+pub use multisig::*;
\ No newline at end of file
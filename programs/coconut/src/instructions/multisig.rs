@@ -0,0 +1,249 @@
+// File: instructions/multisig.rs
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use crate::context::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+pub fn create_multisig(ctx: Context<CreateMultisig>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    validate_members(&members, threshold)?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.create_key = ctx.accounts.create_key.key();
+    multisig.members = members;
+    multisig.threshold = threshold;
+    multisig.member_set_seqno = 0;
+    multisig.proposal_count = 0;
+    multisig.bump = ctx.bumps.multisig;
+    multisig.signer_bump = ctx.bumps.multisig_signer;
+
+    emit!(MultisigCreated {
+        multisig: multisig.key(),
+        signer: ctx.accounts.multisig_signer.key(),
+        members: multisig.members.clone(),
+        threshold,
+    });
+
+    Ok(())
+}
+
+/// Proposes an instruction for the multisig signer to invoke. The proposer's approval is
+/// counted immediately.
+pub fn propose_multisig_transaction(
+    ctx: Context<ProposeMultisigTransaction>,
+    program_id: Pubkey,
+    accounts: Vec<ProposalAccountMeta>,
+    data: Vec<u8>,
+) -> Result<()> {
+    require!(
+        accounts.len() <= MAX_PROPOSAL_ACCOUNTS && data.len() <= MAX_PROPOSAL_DATA_LEN,
+        ErrorCode::ProposalTooLarge
+    );
+
+    let multisig = &mut ctx.accounts.multisig;
+    let proposer_index = multisig
+        .member_index(&ctx.accounts.proposer.key())
+        .ok_or(ErrorCode::NotMultisigMember)?;
+
+    let proposal_id = multisig.proposal_count;
+    multisig.proposal_count = multisig.proposal_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+
+    let mut approvals = vec![false; multisig.members.len()];
+    approvals[proposer_index] = true;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.multisig = multisig.key();
+    proposal.proposal_id = proposal_id;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.program_id = program_id;
+    proposal.accounts = accounts;
+    proposal.data = data;
+    proposal.approvals = approvals;
+    proposal.member_set_seqno = multisig.member_set_seqno;
+    proposal.executed = false;
+    proposal.bump = ctx.bumps.proposal;
+
+    emit!(MultisigTransactionProposed {
+        multisig: proposal.multisig,
+        proposal: proposal.key(),
+        proposal_id,
+        proposer: proposal.proposer,
+        program_id,
+    });
+
+    Ok(())
+}
+
+pub fn approve_multisig_transaction(ctx: Context<ApproveMultisigTransaction>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    require!(!proposal.executed, ErrorCode::ProposalAlreadyExecuted);
+    require!(
+        proposal.member_set_seqno == multisig.member_set_seqno,
+        ErrorCode::MultisigMembersChanged
+    );
+
+    let member_index = multisig
+        .member_index(&ctx.accounts.member.key())
+        .ok_or(ErrorCode::NotMultisigMember)?;
+    proposal.approvals[member_index] = true;
+
+    emit!(MultisigTransactionApproved {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        member: ctx.accounts.member.key(),
+        approvals: proposal.approval_count() as u8,
+    });
+
+    Ok(())
+}
+
+/// Invokes the proposed instruction signed by the multisig signer. Every account it uses,
+/// including the target program, must be passed as remaining accounts.
+pub fn execute_multisig_transaction(ctx: Context<ExecuteMultisigTransaction>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    require!(multisig.member_index(&ctx.accounts.member.key()).is_some(), ErrorCode::NotMultisigMember);
+    require!(!proposal.executed, ErrorCode::ProposalAlreadyExecuted);
+    require!(
+        proposal.member_set_seqno == multisig.member_set_seqno,
+        ErrorCode::MultisigMembersChanged
+    );
+    require!(
+        proposal.approval_count() >= multisig.threshold as usize,
+        ErrorCode::NotEnoughApprovals
+    );
+
+    proposal.executed = true;
+
+    let instruction = Instruction {
+        program_id: proposal.program_id,
+        accounts: proposal
+            .accounts
+            .iter()
+            .map(|meta| AccountMeta {
+                pubkey: meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: proposal.data.clone(),
+    };
+
+    let multisig_key = multisig.key();
+    let signer_seeds: &[&[u8]] = &[b"multisig_signer", multisig_key.as_ref(), &[multisig.signer_bump]];
+    invoke_signed(&instruction, ctx.remaining_accounts, &[signer_seeds])?;
+
+    emit!(MultisigTransactionExecuted {
+        multisig: multisig_key,
+        proposal: proposal.key(),
+        executor: ctx.accounts.member.key(),
+    });
+
+    Ok(())
+}
+
+/// Replaces the members and threshold. Only callable by the multisig signer, i.e. through an
+/// executed proposal; pending proposals are invalidated.
+pub fn set_multisig_members(ctx: Context<SetMultisigMembers>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    validate_members(&members, threshold)?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.members = members;
+    multisig.threshold = threshold;
+    multisig.member_set_seqno = multisig.member_set_seqno.checked_add(1).ok_or(ErrorCode::Overflow)?;
+
+    emit!(MultisigMembersUpdated {
+        multisig: multisig.key(),
+        members: multisig.members.clone(),
+        threshold,
+    });
+
+    Ok(())
+}
+
+/// Closes a proposal that has been executed or invalidated by a member change, returning its
+/// rent to the proposer.
+pub fn close_multisig_proposal(ctx: Context<CloseMultisigProposal>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &ctx.accounts.proposal;
+    require!(
+        proposal.executed || proposal.member_set_seqno != multisig.member_set_seqno,
+        ErrorCode::ProposalStillPending
+    );
+
+    emit!(MultisigProposalClosed {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        executed: proposal.executed,
+    });
+
+    Ok(())
+}
+
+fn validate_members(members: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !members.is_empty() && members.len() <= MAX_MULTISIG_MEMBERS,
+        ErrorCode::InvalidMultisigConfig
+    );
+    require!(
+        threshold > 0 && threshold as usize <= members.len(),
+        ErrorCode::InvalidMultisigConfig
+    );
+
+    for (index, member) in members.iter().enumerate() {
+        require!(!members[..index].contains(member), ErrorCode::InvalidMultisigConfig);
+    }
+
+    Ok(())
+}
+
+#[event]
+pub struct MultisigCreated {
+    pub multisig: Pubkey,
+    pub signer: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct MultisigTransactionProposed {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub program_id: Pubkey,
+}
+
+#[event]
+pub struct MultisigTransactionApproved {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub member: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct MultisigTransactionExecuted {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub executor: Pubkey,
+}
+
+#[event]
+pub struct MultisigProposalClosed {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub executed: bool,
+}
+
+#[event]
+pub struct MultisigMembersUpdated {
+    pub multisig: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+}
\ No newline at end of file
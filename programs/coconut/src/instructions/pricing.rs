@@ -0,0 +1,76 @@
+// File: instructions/pricing.rs
+
+use anchor_lang::prelude::*;
+use crate::context::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+pub fn set_pricing_rules(
+    ctx: Context<SetPricingRules>,
+    seasonal_rates: Vec<SeasonalRate>,
+    weekend_surcharge_bps: u16,
+    stay_discounts: Vec<StayDiscount>,
+) -> Result<()> {
+    let pricing_rules = &mut ctx.accounts.pricing_rules;
+    pricing_rules.rental_listing = ctx.accounts.rental_listing.key();
+    pricing_rules.seasonal_rates = seasonal_rates;
+    pricing_rules.weekend_surcharge_bps = weekend_surcharge_bps;
+    pricing_rules.stay_discounts = stay_discounts;
+    pricing_rules.bump = ctx.bumps.pricing_rules;
+    require!(pricing_rules.is_valid(), ErrorCode::InvalidPricingRules);
+
+    ctx.accounts.rental_listing.has_pricing_rules = true;
+
+    emit!(PricingRulesUpdated {
+        rental_listing: pricing_rules.rental_listing,
+        pricing_rules: pricing_rules.key(),
+        seasonal_rate_count: pricing_rules.seasonal_rates.len() as u8,
+        weekend_surcharge_bps,
+        stay_discount_count: pricing_rules.stay_discounts.len() as u8,
+    });
+
+    Ok(())
+}
+
+/// Removes a listing's pricing rules, returning it to a flat `price` per night.
+pub fn clear_pricing_rules(ctx: Context<ClearPricingRules>) -> Result<()> {
+    ctx.accounts.rental_listing.has_pricing_rules = false;
+
+    emit!(PricingRulesCleared {
+        rental_listing: ctx.accounts.rental_listing.key(),
+        pricing_rules: ctx.accounts.pricing_rules.key(),
+    });
+
+    Ok(())
+}
+
+/// Read-only quote of a stay, returned to the caller so frontends can preview the price
+/// `book_room` will charge.
+pub fn quote_booking(ctx: Context<QuoteBooking>, check_in_day: u32, nights: u16) -> Result<u64> {
+    let listing = &ctx.accounts.rental_listing;
+    require!((1..=MAX_STAY_NIGHTS).contains(&nights), ErrorCode::InvalidStayDates);
+
+    let pricing_rules = ctx.accounts.pricing_rules.as_deref();
+    require!(pricing_rules.is_some() == listing.has_pricing_rules, ErrorCode::PricingRulesMismatch);
+
+    let amount = listing
+        .quote(pricing_rules, check_in_day, nights)
+        .ok_or(ErrorCode::Overflow)?;
+
+    Ok(amount)
+}
+
+#[event]
+pub struct PricingRulesUpdated {
+    pub rental_listing: Pubkey,
+    pub pricing_rules: Pubkey,
+    pub seasonal_rate_count: u8,
+    pub weekend_surcharge_bps: u16,
+    pub stay_discount_count: u8,
+}
+
+#[event]
+pub struct PricingRulesCleared {
+    pub rental_listing: Pubkey,
+    pub pricing_rules: Pubkey,
+}
\ No newline at end of file
@@ -0,0 +1,241 @@
+// File: instructions/protocol_config.rs
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use crate::context::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+pub fn initialize_protocol_config(ctx: Context<InitializeProtocolConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.protocol_config;
+    config.admin = ctx.accounts.admin.key();
+    config.pending_admin = None;
+    config.verifiers = Vec::new();
+    config.protocol_fee_bps = 0;
+    config.revenue_share_bps = 0;
+    config.max_ltv_bps = 0;
+    config.bump = ctx.bumps.protocol_config;
+
+    emit!(ProtocolConfigInitialized {
+        config: config.key(),
+        admin: config.admin,
+    });
+
+    Ok(())
+}
+
+pub fn add_verifier(ctx: Context<ManageVerifiers>, verifier: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.protocol_config;
+    require!(!config.verifiers.contains(&verifier), ErrorCode::VerifierAlreadyRegistered);
+    require!(config.verifiers.len() < MAX_VERIFIERS, ErrorCode::TooManyVerifiers);
+
+    config.verifiers.push(verifier);
+
+    emit!(VerifierAdded {
+        config: config.key(),
+        verifier,
+    });
+
+    Ok(())
+}
+
+pub fn remove_verifier(ctx: Context<ManageVerifiers>, verifier: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.protocol_config;
+    let index = config
+        .verifiers
+        .iter()
+        .position(|key| *key == verifier)
+        .ok_or(ErrorCode::VerifierNotRegistered)?;
+
+    config.verifiers.swap_remove(index);
+
+    emit!(VerifierRemoved {
+        config: config.key(),
+        verifier,
+    });
+
+    Ok(())
+}
+
+/// Proposes handing the protocol admin role, which manages verifiers and protocol parameters,
+/// to `new_admin`, e.g. a multisig signer. It takes effect once the new admin accepts.
+pub fn propose_protocol_admin(ctx: Context<UpdateProtocolConfig>, new_admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.protocol_config;
+    config.pending_admin = Some(new_admin);
+
+    emit!(ProtocolAdminProposed {
+        config: config.key(),
+        admin: config.admin,
+        pending_admin: new_admin,
+    });
+
+    Ok(())
+}
+
+pub fn accept_protocol_admin(ctx: Context<AcceptProtocolAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.protocol_config;
+    let previous_admin = config.admin;
+    config.admin = ctx.accounts.new_admin.key();
+    config.pending_admin = None;
+
+    emit!(ProtocolAdminUpdated {
+        config: config.key(),
+        previous_admin,
+        new_admin: config.admin,
+    });
+
+    Ok(())
+}
+
+/// Sets the fee charged on rent paid out to hotels. Existing bookings keep the fee they were
+/// made under.
+pub fn set_protocol_fee(ctx: Context<UpdateProtocolConfig>, protocol_fee_bps: u16) -> Result<()> {
+    require!(protocol_fee_bps <= MAX_PROTOCOL_FEE_BPS, ErrorCode::InvalidProtocolFee);
+
+    let config = &mut ctx.accounts.protocol_config;
+    config.protocol_fee_bps = protocol_fee_bps;
+
+    emit!(ProtocolFeeUpdated {
+        config: config.key(),
+        protocol_fee_bps,
+    });
+
+    Ok(())
+}
+
+/// Sets the share of rent paid out to hotels that goes to COCO stakers. Existing bookings keep
+/// the share they were made under.
+pub fn set_revenue_share(ctx: Context<UpdateProtocolConfig>, revenue_share_bps: u16) -> Result<()> {
+    require!(revenue_share_bps <= MAX_REVENUE_SHARE_BPS, ErrorCode::InvalidRevenueShare);
+
+    let config = &mut ctx.accounts.protocol_config;
+    config.revenue_share_bps = revenue_share_bps;
+
+    emit!(RevenueShareUpdated {
+        config: config.key(),
+        revenue_share_bps,
+    });
+
+    Ok(())
+}
+
+/// Sets the loan-to-value limit on COCO issued against hotel appraisals. Lowering it doesn't
+/// affect COCO already issued but blocks further issuance above the new limit.
+pub fn set_max_ltv(ctx: Context<UpdateProtocolConfig>, max_ltv_bps: u16) -> Result<()> {
+    require!(max_ltv_bps as u64 <= BASIS_POINTS, ErrorCode::InvalidLtv);
+
+    let config = &mut ctx.accounts.protocol_config;
+    config.max_ltv_bps = max_ltv_bps;
+
+    emit!(MaxLtvUpdated {
+        config: config.key(),
+        max_ltv_bps,
+    });
+
+    Ok(())
+}
+
+/// Creates the treasury token account collecting protocol fees paid in `mint`. Bookings paid
+/// in a mint can't be checked in or cancelled until its treasury exists.
+pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+    emit!(TreasuryInitialized {
+        treasury: ctx.accounts.treasury.key(),
+        mint: ctx.accounts.mint.key(),
+    });
+
+    Ok(())
+}
+
+pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    require!(ctx.accounts.treasury.amount >= amount, ErrorCode::InsufficientFunds);
+
+    let config_seeds: &[&[u8]] = &[b"protocol_config", &[ctx.accounts.protocol_config.bump]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.treasury.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.protocol_config.to_account_info(),
+            },
+            &[config_seeds],
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    emit!(TreasuryWithdrawn {
+        treasury: ctx.accounts.treasury.key(),
+        mint: ctx.accounts.mint.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ProtocolConfigInitialized {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct VerifierAdded {
+    pub config: Pubkey,
+    pub verifier: Pubkey,
+}
+
+#[event]
+pub struct VerifierRemoved {
+    pub config: Pubkey,
+    pub verifier: Pubkey,
+}
+
+#[event]
+pub struct ProtocolFeeUpdated {
+    pub config: Pubkey,
+    pub protocol_fee_bps: u16,
+}
+
+#[event]
+pub struct RevenueShareUpdated {
+    pub config: Pubkey,
+    pub revenue_share_bps: u16,
+}
+
+#[event]
+pub struct ProtocolAdminProposed {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct ProtocolAdminUpdated {
+    pub config: Pubkey,
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct MaxLtvUpdated {
+    pub config: Pubkey,
+    pub max_ltv_bps: u16,
+}
+
+#[event]
+pub struct TreasuryInitialized {
+    pub treasury: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub treasury: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
\ No newline at end of file
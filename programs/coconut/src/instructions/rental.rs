@@ -3,67 +3,351 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface;
 use crate::context::*;
+use crate::nft::{self, MintNftAccounts, NftMetadata};
 use crate::state::*;
 use crate::errors::ErrorCode;
 
-pub fn rent_room(ctx: Context<RentRoom>, room_number: u16, duration: i64, usdc_amount: u64) -> Result<()> {
+const RECEIPT_SYMBOL: &str = "COCOB";
+
+/// Books a stay. When paying in COCO, `max_coco_in` caps the rent plus deposit charged at the
+/// pool's TWAP; it is ignored when paying in the listing's mint.
+pub fn book_room(ctx: Context<BookRoom>, check_in_day: u32, nights: u16, max_coco_in: u64) -> Result<()> {
     let hotel = &mut ctx.accounts.hotel;
-    require!(room_number <= hotel.room_count, ErrorCode::InvalidRoomNumber);
+    let room = &ctx.accounts.room;
+    let listing = &ctx.accounts.rental_listing;
+    // Rent in escrow is only released on check-in, which requires a verified hotel
+    require!(hotel.is_verified, ErrorCode::HotelNotVerified);
+    require!(room.room_number >= 1 && room.room_number <= hotel.room_count, ErrorCode::InvalidRoomNumber);
+    require!(listing.is_active, ErrorCode::ListingInactive);
+
+    let now = Clock::get()?.unix_timestamp;
+    let today = (now / SECONDS_PER_DAY) as u32;
+    require!(check_in_day >= today, ErrorCode::InvalidStayDates);
+    require!((1..=MAX_STAY_NIGHTS).contains(&nights), ErrorCode::InvalidStayDates);
+
+    // Reserve every night of the stay, failing on any overlap with an existing booking
+    let window = check_in_day / CALENDAR_WINDOW_DAYS;
+    init_calendar(&mut ctx.accounts.calendar, room.key(), window, ctx.bumps.calendar);
+    require!(
+        ctx.accounts.next_calendar.is_some() == RoomCalendar::spans_next_window(check_in_day, nights),
+        ErrorCode::CalendarMismatch
+    );
+    if let (Some(next_calendar), Some(bump)) = (ctx.accounts.next_calendar.as_mut(), ctx.bumps.next_calendar) {
+        init_calendar(next_calendar, room.key(), window + 1, bump);
+    }
+
+    for day in check_in_day..check_in_day + nights as u32 {
+        let calendar = match ctx.accounts.next_calendar.as_mut() {
+            Some(next_calendar) if day / CALENDAR_WINDOW_DAYS != window => next_calendar,
+            _ => &mut ctx.accounts.calendar,
+        };
+        require!(!calendar.is_booked(day), ErrorCode::RoomUnavailable);
+        calendar.reserve(day);
+    }
+
+    let pricing_rules = ctx.accounts.pricing_rules.as_deref().map(|rules| &**rules);
+    require!(pricing_rules.is_some() == listing.has_pricing_rules, ErrorCode::PricingRulesMismatch);
+    let listing_amount = listing
+        .quote(pricing_rules, check_in_day, nights)
+        .ok_or(ErrorCode::Overflow)?;
 
-    // Transfer USDC from renter to hotel owner
-    token_interface::transfer(
+    // Paying in COCO converts the quote through the pool and applies the staker discount
+    let payment_mint = &ctx.accounts.payment_mint;
+    let listing_deposit = ctx.accounts.room_type.security_deposit;
+    let (amount, security_deposit, discount_bps) = match ctx.accounts.liquidity_pool.as_deref() {
+        Some(pool) => {
+            require!(pool.usdc_mint == listing.mint, ErrorCode::InvalidPaymentMint);
+            require!(payment_mint.key() == pool.coco_mint, ErrorCode::InvalidPaymentMint);
+
+            let coco_amount = pool
+                .usdc_to_coco(listing_amount, now)
+                .ok_or(ErrorCode::PoolPriceUnavailable)?;
+            // Only stake held since before the booking counts, so it can't be staked and
+            // unstaked around it
+            let discount_bps = ctx
+                .accounts
+                .stake_account
+                .as_ref()
+                .filter(|stake| now - stake.last_stake_timestamp >= MIN_DISCOUNT_STAKE_AGE)
+                .map_or(0, |stake| staker_discount_bps(stake.staked_amount, payment_mint.decimals));
+            let discount = (coco_amount as u128 * discount_bps as u128 / BASIS_POINTS as u128) as u64;
+            let coco_deposit = pool
+                .usdc_to_coco(listing_deposit, now)
+                .ok_or(ErrorCode::PoolPriceUnavailable)?;
+
+            let coco_amount = coco_amount - discount;
+            require!(
+                coco_amount.checked_add(coco_deposit).ok_or(ErrorCode::Overflow)? <= max_coco_in,
+                ErrorCode::SlippageExceeded
+            );
+
+            (coco_amount, coco_deposit, discount_bps)
+        }
+        None => {
+            require!(payment_mint.key() == listing.mint, ErrorCode::InvalidPaymentMint);
+            (listing_amount, listing_deposit, 0)
+        }
+    };
+
+    // Hold the payment and security deposit in the booking escrow until the renter checks in
+    token_interface::transfer_checked(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
-            token_interface::Transfer {
-                from: ctx.accounts.renter_usdc_account.to_account_info(),
-                to: ctx.accounts.hotel_owner_usdc_account.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.renter_payment_account.to_account_info(),
+                mint: ctx.accounts.payment_mint.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
                 authority: ctx.accounts.renter.to_account_info(),
             },
         ),
-        usdc_amount,
+        amount.checked_add(security_deposit).ok_or(ErrorCode::Overflow)?,
+        ctx.accounts.payment_mint.decimals,
     )?;
 
-    emit!(RoomRented {
-        hotel: hotel.key(),
-        renter: ctx.accounts.renter.key(),
-        room_number,
-        duration,
-        amount: usdc_amount,
+    hotel.active_bookings = hotel.active_bookings.checked_add(1).ok_or(ErrorCode::Overflow)?;
+
+    let booking = &mut ctx.accounts.booking;
+    booking.hotel = hotel.key();
+    booking.room = room.key();
+    booking.renter = ctx.accounts.renter.key();
+    booking.check_in_day = check_in_day;
+    booking.nights = nights;
+    booking.payment_mint = ctx.accounts.payment_mint.key();
+    booking.amount = amount;
+    booking.cancellation_policy = ctx.accounts.room_type.cancellation_policy;
+    booking.status = BookingStatus::Reserved;
+    booking.created_at = now;
+    booking.transferable_receipt = hotel.transferable_receipts;
+    booking.security_deposit = security_deposit;
+    booking.damage_claim_window_days = ctx.accounts.room_type.damage_claim_window_days;
+    booking.deposit_status = if security_deposit > 0 {
+        DepositStatus::Held
+    } else {
+        DepositStatus::Released
+    };
+    booking.damage_claim = 0;
+    booking.checked_out_at = 0;
+    booking.protocol_fee_bps = ctx.accounts.protocol_config.protocol_fee_bps;
+    // Only rent paid in the distributor's reward mint can be shared with stakers
+    booking.revenue_share_bps = if booking.payment_mint == ctx.accounts.revenue_distributor.reward_mint {
+        ctx.accounts.protocol_config.revenue_share_bps
+    } else {
+        0
+    };
+    booking.bump = ctx.bumps.booking;
+
+    // Mint the booking receipt to the renter
+    let booking_key = booking.key();
+    let receipt_seeds: &[&[u8]] = &[
+        b"booking_receipt",
+        booking_key.as_ref(),
+        &[ctx.bumps.receipt_mint],
+    ];
+
+    nft::mint_nft(
+        MintNftAccounts {
+            payer: &ctx.accounts.renter.to_account_info(),
+            mint: &ctx.accounts.receipt_mint.to_account_info(),
+            recipient: &ctx.accounts.renter.to_account_info(),
+            recipient_token_account: &ctx.accounts.renter_receipt_account.to_account_info(),
+            token_program: &ctx.accounts.receipt_token_program.to_account_info(),
+            associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+        },
+        receipt_seeds,
+        NftMetadata {
+            name: format!("{} #{}", hotel.name, room.room_number),
+            symbol: RECEIPT_SYMBOL.to_string(),
+            uri: String::new(),
+            additional_metadata: vec![
+                ("hotel".to_string(), booking.hotel.to_string()),
+                ("room".to_string(), room.room_number.to_string()),
+                ("check_in".to_string(), booking.check_in_timestamp().to_string()),
+                ("check_out".to_string(), booking.check_out_timestamp().to_string()),
+                ("payment_mint".to_string(), booking.payment_mint.to_string()),
+                ("amount".to_string(), amount.to_string()),
+            ],
+        },
+        !booking.transferable_receipt,
+    )?;
+
+    emit!(RoomBooked {
+        booking: booking.key(),
+        hotel: booking.hotel,
+        room: booking.room,
+        renter: booking.renter,
+        room_number: room.room_number,
+        check_in_day,
+        nights,
+        payment_mint: booking.payment_mint,
+        amount,
+        discount_bps,
+        security_deposit,
+        protocol_fee: bps_of(amount, booking.protocol_fee_bps).ok_or(ErrorCode::Overflow)?,
+        revenue_share: bps_of(amount, booking.revenue_share_bps).ok_or(ErrorCode::Overflow)?,
+        receipt_mint: ctx.accounts.receipt_mint.key(),
     });
 
     Ok(())
 }
 
 pub fn create_rental_listing(ctx: Context<CreateRentalListing>, room_number: u16, price: u64) -> Result<()> {
+    let hotel = &mut ctx.accounts.hotel;
+    require!(room_number >= 1 && room_number <= hotel.room_count, ErrorCode::InvalidRoomNumber);
+    hotel.listing_count = hotel.listing_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+
     let listing = &mut ctx.accounts.rental_listing;
     listing.owner = ctx.accounts.owner.key();
+    listing.hotel = hotel.key();
+    listing.room = ctx.accounts.room.key();
     listing.room_number = room_number;
+    listing.mint = ctx.accounts.payment_mint.key();
     listing.price = price;
     listing.is_active = true;
+    listing.has_pricing_rules = false;
+    listing.bump = ctx.bumps.rental_listing;
 
     emit!(RentalListingCreated {
         listing: listing.key(),
         owner: listing.owner,
+        hotel: listing.hotel,
+        room: listing.room,
         room_number,
+        mint: listing.mint,
         price,
     });
 
     Ok(())
 }
 
+pub fn update_listing_price(ctx: Context<UpdateRentalListing>, price: u64) -> Result<()> {
+    let listing = &mut ctx.accounts.rental_listing;
+    let old_price = listing.price;
+    listing.price = price;
+
+    emit!(RentalListingPriceUpdated {
+        listing: listing.key(),
+        old_price,
+        new_price: price,
+    });
+
+    Ok(())
+}
+
+pub fn deactivate_listing(ctx: Context<UpdateRentalListing>) -> Result<()> {
+    let listing = &mut ctx.accounts.rental_listing;
+    require!(listing.is_active, ErrorCode::ListingInactive);
+    listing.is_active = false;
+
+    emit!(RentalListingDeactivated {
+        listing: listing.key(),
+        hotel: listing.hotel,
+    });
+
+    Ok(())
+}
+
+pub fn reactivate_listing(ctx: Context<UpdateRentalListing>) -> Result<()> {
+    let listing = &mut ctx.accounts.rental_listing;
+    require!(!listing.is_active, ErrorCode::ListingAlreadyActive);
+    listing.is_active = true;
+
+    emit!(RentalListingReactivated {
+        listing: listing.key(),
+        hotel: listing.hotel,
+    });
+
+    Ok(())
+}
+
+pub fn close_listing(ctx: Context<CloseRentalListing>) -> Result<()> {
+    // Pricing rules are closed alongside the listing so they can't leak into a new one
+    require!(
+        ctx.accounts.pricing_rules.is_some() == ctx.accounts.rental_listing.has_pricing_rules,
+        ErrorCode::PricingRulesMismatch
+    );
+
+    let hotel = &mut ctx.accounts.hotel;
+    hotel.listing_count = hotel.listing_count.saturating_sub(1);
+
+    emit!(RentalListingClosed {
+        listing: ctx.accounts.rental_listing.key(),
+        hotel: hotel.key(),
+    });
+
+    Ok(())
+}
+
+fn staker_discount_bps(staked_amount: u64, decimals: u8) -> u16 {
+    let unit = 10u64.saturating_pow(decimals as u32);
+
+    STAKER_DISCOUNT_TIERS
+        .iter()
+        .find(|(min_staked, _)| staked_amount >= min_staked.saturating_mul(unit))
+        .map_or(0, |(_, discount_bps)| *discount_bps)
+}
+
+fn init_calendar(calendar: &mut RoomCalendar, room: Pubkey, window: u32, bump: u8) {
+    if calendar.room == Pubkey::default() {
+        calendar.room = room;
+        calendar.window = window;
+        calendar.booked_nights = 0;
+        calendar.bump = bump;
+    }
+}
+
 #[event]
-pub struct RoomRented {
+pub struct RoomBooked {
+    pub booking: Pubkey,
     pub hotel: Pubkey,
+    pub room: Pubkey,
     pub renter: Pubkey,
     pub room_number: u16,
-    pub duration: i64,
+    pub check_in_day: u32,
+    pub nights: u16,
+    pub payment_mint: Pubkey,
     pub amount: u64,
+    pub discount_bps: u16,
+    pub security_deposit: u64,
+    /// Fee the protocol takes from the rent when it is paid out to the hotel
+    pub protocol_fee: u64,
+    /// Share of the rent distributed to COCO stakers when it is paid out to the hotel
+    pub revenue_share: u64,
+    pub receipt_mint: Pubkey,
 }
 
 #[event]
 pub struct RentalListingCreated {
     pub listing: Pubkey,
     pub owner: Pubkey,
+    pub hotel: Pubkey,
+    pub room: Pubkey,
+    pub mint: Pubkey,
     pub room_number: u16,
     pub price: u64,
+}
+
+#[event]
+pub struct RentalListingPriceUpdated {
+    pub listing: Pubkey,
+    pub old_price: u64,
+    pub new_price: u64,
+}
+
+#[event]
+pub struct RentalListingDeactivated {
+    pub listing: Pubkey,
+    pub hotel: Pubkey,
+}
+
+#[event]
+pub struct RentalListingReactivated {
+    pub listing: Pubkey,
+    pub hotel: Pubkey,
+}
+
+#[event]
+pub struct RentalListingClosed {
+    pub listing: Pubkey,
+    pub hotel: Pubkey,
 }
\ No newline at end of file
@@ -0,0 +1,163 @@
+// File: instructions/resale.rs
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use crate::context::*;
+use crate::nft::{self, TransferNftAccounts};
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+pub fn list_booking_for_resale(ctx: Context<ListBookingForResale>, price: u64) -> Result<()> {
+    let booking = &ctx.accounts.booking;
+    require!(booking.transferable_receipt, ErrorCode::BookingNotResellable);
+    require!(booking.status == BookingStatus::Reserved, ErrorCode::InvalidBookingStatus);
+    require!(
+        Clock::get()?.unix_timestamp < booking.check_in_timestamp(),
+        ErrorCode::CancellationWindowClosed
+    );
+    require!(price > 0, ErrorCode::InvalidResalePrice);
+
+    let resale_listing = &mut ctx.accounts.resale_listing;
+    resale_listing.booking = booking.key();
+    resale_listing.seller = ctx.accounts.seller.key();
+    resale_listing.price = price;
+    resale_listing.bump = ctx.bumps.resale_listing;
+
+    emit!(BookingListedForResale {
+        resale_listing: resale_listing.key(),
+        booking: booking.key(),
+        seller: resale_listing.seller,
+        price,
+    });
+
+    Ok(())
+}
+
+pub fn cancel_resale_listing(ctx: Context<CancelResaleListing>) -> Result<()> {
+    let resale_listing = &ctx.accounts.resale_listing;
+
+    emit!(ResaleListingCancelled {
+        resale_listing: resale_listing.key(),
+        booking: resale_listing.booking,
+        seller: resale_listing.seller,
+    });
+
+    Ok(())
+}
+
+/// Buys a listed booking: the buyer pays the asking price plus any security deposit, the hotel
+/// takes its resale royalty, and the booking and its receipt move to the buyer in the same
+/// transaction.
+pub fn buy_resale_booking(ctx: Context<BuyResaleBooking>, max_price: u64) -> Result<()> {
+    let price = ctx.accounts.resale_listing.price;
+    require!(price <= max_price, ErrorCode::SlippageExceeded);
+
+    let booking = &ctx.accounts.booking;
+    require!(booking.status == BookingStatus::Reserved, ErrorCode::InvalidBookingStatus);
+    require!(
+        Clock::get()?.unix_timestamp < booking.check_in_timestamp(),
+        ErrorCode::CancellationWindowClosed
+    );
+
+    let royalty = u64::try_from(
+        price as u128 * ctx.accounts.hotel.resale_royalty_bps as u128 / BASIS_POINTS as u128,
+    )
+    .map_err(|_| ErrorCode::Overflow)?;
+    // The escrowed security deposit now belongs to the buyer, so they reimburse the seller for it
+    let seller_proceeds = price
+        .checked_sub(royalty)
+        .and_then(|proceeds| proceeds.checked_add(booking.security_deposit))
+        .ok_or(ErrorCode::Overflow)?;
+
+    if royalty > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.buyer_payment_account.to_account_info(),
+                    mint: ctx.accounts.payment_mint.to_account_info(),
+                    to: ctx.accounts.hotel_payout_account.to_account_info(),
+                    authority: ctx.accounts.buyer.to_account_info(),
+                },
+            ),
+            royalty,
+            ctx.accounts.payment_mint.decimals,
+        )?;
+    }
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.buyer_payment_account.to_account_info(),
+                mint: ctx.accounts.payment_mint.to_account_info(),
+                to: ctx.accounts.seller_payment_account.to_account_info(),
+                authority: ctx.accounts.buyer.to_account_info(),
+            },
+        ),
+        seller_proceeds,
+        ctx.accounts.payment_mint.decimals,
+    )?;
+
+    let booking_key = booking.key();
+    let receipt_seeds: &[&[u8]] = &[
+        b"booking_receipt",
+        booking_key.as_ref(),
+        &[ctx.bumps.receipt_mint],
+    ];
+
+    nft::transfer_nft(
+        TransferNftAccounts {
+            payer: &ctx.accounts.buyer.to_account_info(),
+            mint: &ctx.accounts.receipt_mint.to_account_info(),
+            holder_token_account: &ctx.accounts.seller_receipt_account.to_account_info(),
+            recipient: &ctx.accounts.buyer.to_account_info(),
+            recipient_token_account: &ctx.accounts.buyer_receipt_account.to_account_info(),
+            token_program: &ctx.accounts.receipt_token_program.to_account_info(),
+            associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+        },
+        receipt_seeds,
+    )?;
+
+    let booking = &mut ctx.accounts.booking;
+    booking.renter = ctx.accounts.buyer.key();
+
+    emit!(BookingResold {
+        resale_listing: ctx.accounts.resale_listing.key(),
+        booking: booking.key(),
+        hotel: booking.hotel,
+        seller: ctx.accounts.seller.key(),
+        buyer: booking.renter,
+        price,
+        royalty,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct BookingListedForResale {
+    pub resale_listing: Pubkey,
+    pub booking: Pubkey,
+    pub seller: Pubkey,
+    pub price: u64,
+}
+
+#[event]
+pub struct ResaleListingCancelled {
+    pub resale_listing: Pubkey,
+    pub booking: Pubkey,
+    pub seller: Pubkey,
+}
+
+#[event]
+pub struct BookingResold {
+    pub resale_listing: Pubkey,
+    pub booking: Pubkey,
+    pub hotel: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub royalty: u64,
+}
\ No newline at end of file
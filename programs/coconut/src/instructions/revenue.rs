@@ -0,0 +1,75 @@
+// File: instructions/revenue.rs
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use crate::context::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+pub fn initialize_revenue_distributor(ctx: Context<InitializeRevenueDistributor>) -> Result<()> {
+    let distributor = &mut ctx.accounts.revenue_distributor;
+    distributor.stake_mint = ctx.accounts.stake_mint.key();
+    distributor.reward_mint = ctx.accounts.reward_mint.key();
+    distributor.rewards = RewardAccumulator::default();
+    distributor.bump = ctx.bumps.revenue_distributor;
+
+    emit!(RevenueDistributorInitialized {
+        distributor: distributor.key(),
+        stake_mint: distributor.stake_mint,
+        reward_mint: distributor.reward_mint,
+    });
+
+    Ok(())
+}
+
+/// Pays out the staker's share of booking revenue accrued since their last claim.
+pub fn claim_revenue(ctx: Context<ClaimRevenue>) -> Result<()> {
+    let distributor = &ctx.accounts.revenue_distributor;
+    let stake_account = &mut ctx.accounts.stake_account;
+    distributor
+        .rewards
+        .settle(stake_account.staked_amount, stake_account.reward_debt, &mut stake_account.pending_rewards)
+        .ok_or(ErrorCode::Overflow)?;
+    stake_account.reward_debt = distributor.rewards.accrued(stake_account.staked_amount).ok_or(ErrorCode::Overflow)?;
+
+    let amount = stake_account.pending_rewards;
+    require!(amount > 0, ErrorCode::NothingToClaim);
+    stake_account.pending_rewards = 0;
+
+    let distributor_seeds: &[&[u8]] = &[b"revenue_distributor", &[distributor.bump]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.revenue_vault.to_account_info(),
+                mint: ctx.accounts.reward_mint.to_account_info(),
+                to: ctx.accounts.staker_reward_account.to_account_info(),
+                authority: distributor.to_account_info(),
+            },
+            &[distributor_seeds],
+        ),
+        amount,
+        ctx.accounts.reward_mint.decimals,
+    )?;
+
+    emit!(RevenueClaimed {
+        staker: ctx.accounts.staker.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RevenueDistributorInitialized {
+    pub distributor: Pubkey,
+    pub stake_mint: Pubkey,
+    pub reward_mint: Pubkey,
+}
+
+#[event]
+pub struct RevenueClaimed {
+    pub staker: Pubkey,
+    pub amount: u64,
+}
\ No newline at end of file
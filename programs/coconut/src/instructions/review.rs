@@ -0,0 +1,56 @@
+// File: instructions/review.rs
+
+use anchor_lang::prelude::*;
+use crate::context::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Records a review of a completed stay. Only the renter of a checked-out booking can review
+/// it, and the review PDA is derived from the booking, so each stay counts exactly once. Free
+/// stays, the hotel owner's own bookings and stays that haven't ended can't be reviewed, so
+/// ratings can't be inflated cheaply.
+pub fn submit_review(ctx: Context<SubmitReview>, rating: u8, content_hash: [u8; 32]) -> Result<()> {
+    let booking = &ctx.accounts.booking;
+    require!(booking.status == BookingStatus::CheckedOut, ErrorCode::InvalidBookingStatus);
+    require!(booking.amount > 0, ErrorCode::FreeStayNotReviewable);
+    require!(ctx.accounts.reviewer.key() != ctx.accounts.hotel.owner, ErrorCode::Unauthorized);
+    require!(
+        Clock::get()?.unix_timestamp >= booking.check_out_timestamp(),
+        ErrorCode::StayNotEnded
+    );
+    require!((MIN_RATING..=MAX_RATING).contains(&rating), ErrorCode::InvalidRating);
+
+    let review = &mut ctx.accounts.review;
+    review.booking = booking.key();
+    review.hotel = booking.hotel;
+    review.reviewer = ctx.accounts.reviewer.key();
+    review.rating = rating;
+    review.content_hash = content_hash;
+    review.created_at = Clock::get()?.unix_timestamp;
+    review.bump = ctx.bumps.review;
+
+    let hotel = &mut ctx.accounts.hotel;
+    hotel.rating_count = hotel.rating_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+    hotel.rating_sum = hotel.rating_sum.checked_add(rating as u64).ok_or(ErrorCode::Overflow)?;
+
+    emit!(ReviewSubmitted {
+        review: review.key(),
+        booking: review.booking,
+        hotel: review.hotel,
+        reviewer: review.reviewer,
+        rating,
+        content_hash,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ReviewSubmitted {
+    pub review: Pubkey,
+    pub booking: Pubkey,
+    pub hotel: Pubkey,
+    pub reviewer: Pubkey,
+    pub rating: u8,
+    pub content_hash: [u8; 32],
+}
\ No newline at end of file
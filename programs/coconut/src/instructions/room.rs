@@ -0,0 +1,216 @@
+// File: instructions/room.rs
+
+use anchor_lang::prelude::*;
+use crate::context::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+pub fn create_room_type(ctx: Context<CreateRoomType>, params: RoomTypeParams) -> Result<()> {
+    let RoomTypeParams {
+        name,
+        capacity,
+        amenities,
+        cancellation_policy,
+        security_deposit,
+        damage_claim_window_days,
+    } = params;
+    require!(!name.is_empty() && name.len() <= MAX_ROOM_TYPE_NAME_LEN, ErrorCode::InvalidRoomTypeName);
+    require!(capacity > 0, ErrorCode::InvalidRoomCapacity);
+    require!(cancellation_policy.is_valid(), ErrorCode::InvalidCancellationPolicy);
+    require!(
+        damage_claim_window_days <= MAX_DAMAGE_CLAIM_WINDOW_DAYS,
+        ErrorCode::InvalidDamageClaimWindow
+    );
+
+    let hotel = &mut ctx.accounts.hotel;
+    let type_id = hotel.room_type_count;
+    hotel.room_type_count = hotel.room_type_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+    hotel.open_room_types = hotel.open_room_types.checked_add(1).ok_or(ErrorCode::Overflow)?;
+
+    let room_type = &mut ctx.accounts.room_type;
+    room_type.hotel = hotel.key();
+    room_type.type_id = type_id;
+    room_type.name = name;
+    room_type.capacity = capacity;
+    room_type.amenities = amenities;
+    room_type.cancellation_policy = cancellation_policy;
+    room_type.security_deposit = security_deposit;
+    room_type.damage_claim_window_days = damage_claim_window_days;
+    room_type.open_rooms = 0;
+    room_type.bump = ctx.bumps.room_type;
+
+    emit!(RoomTypeCreated {
+        hotel: room_type.hotel,
+        room_type: room_type.key(),
+        type_id,
+        name: room_type.name.clone(),
+        capacity,
+        amenities,
+        security_deposit,
+        damage_claim_window_days,
+    });
+
+    Ok(())
+}
+
+pub fn update_room_type(ctx: Context<UpdateRoomType>, update: RoomTypeUpdate) -> Result<()> {
+    let RoomTypeUpdate {
+        name,
+        capacity,
+        amenities,
+        cancellation_policy,
+        security_deposit,
+        damage_claim_window_days,
+    } = update;
+    let room_type = &mut ctx.accounts.room_type;
+
+    if let Some(name) = name {
+        require!(!name.is_empty() && name.len() <= MAX_ROOM_TYPE_NAME_LEN, ErrorCode::InvalidRoomTypeName);
+        room_type.name = name;
+    }
+
+    if let Some(capacity) = capacity {
+        require!(capacity > 0, ErrorCode::InvalidRoomCapacity);
+        room_type.capacity = capacity;
+    }
+
+    if let Some(amenities) = amenities {
+        room_type.amenities = amenities;
+    }
+
+    // Existing bookings keep the policy they were made under
+    if let Some(cancellation_policy) = cancellation_policy {
+        require!(cancellation_policy.is_valid(), ErrorCode::InvalidCancellationPolicy);
+        room_type.cancellation_policy = cancellation_policy;
+    }
+
+    if let Some(security_deposit) = security_deposit {
+        room_type.security_deposit = security_deposit;
+    }
+
+    if let Some(damage_claim_window_days) = damage_claim_window_days {
+        require!(
+            damage_claim_window_days <= MAX_DAMAGE_CLAIM_WINDOW_DAYS,
+            ErrorCode::InvalidDamageClaimWindow
+        );
+        room_type.damage_claim_window_days = damage_claim_window_days;
+    }
+
+    emit!(RoomTypeUpdated {
+        hotel: room_type.hotel,
+        room_type: room_type.key(),
+        name: room_type.name.clone(),
+        capacity: room_type.capacity,
+        amenities: room_type.amenities,
+        security_deposit: room_type.security_deposit,
+        damage_claim_window_days: room_type.damage_claim_window_days,
+    });
+
+    Ok(())
+}
+
+pub fn create_room(ctx: Context<CreateRoom>, room_number: u16) -> Result<()> {
+    let hotel = &mut ctx.accounts.hotel;
+    require!((1..=hotel.room_count).contains(&room_number), ErrorCode::InvalidRoomNumber);
+    hotel.max_room_number = hotel.max_room_number.max(room_number);
+    hotel.open_rooms = hotel.open_rooms.checked_add(1).ok_or(ErrorCode::Overflow)?;
+
+    let room_type = &mut ctx.accounts.room_type;
+    room_type.open_rooms = room_type.open_rooms.checked_add(1).ok_or(ErrorCode::Overflow)?;
+
+    let room = &mut ctx.accounts.room;
+    room.hotel = hotel.key();
+    room.room_number = room_number;
+    room.room_type = room_type.key();
+    room.bump = ctx.bumps.room;
+
+    emit!(RoomCreated {
+        hotel: room.hotel,
+        room: room.key(),
+        room_number,
+        room_type: room.room_type,
+    });
+
+    Ok(())
+}
+
+/// Closes a room without a rental listing. Its calendars stay behind, so it can only be closed
+/// while the hotel has no active bookings.
+pub fn close_room(ctx: Context<CloseRoom>) -> Result<()> {
+    require!(ctx.accounts.rental_listing.data_is_empty(), ErrorCode::RoomHasListing);
+
+    let hotel = &mut ctx.accounts.hotel;
+    require!(hotel.active_bookings == 0, ErrorCode::HotelHasActiveBookings);
+    hotel.open_rooms = hotel.open_rooms.saturating_sub(1);
+    let room_type = &mut ctx.accounts.room_type;
+    room_type.open_rooms = room_type.open_rooms.saturating_sub(1);
+
+    emit!(RoomClosed {
+        hotel: hotel.key(),
+        room: ctx.accounts.room.key(),
+        room_number: ctx.accounts.room.room_number,
+    });
+
+    Ok(())
+}
+
+pub fn close_room_type(ctx: Context<CloseRoomType>) -> Result<()> {
+    let room_type = &ctx.accounts.room_type;
+    require!(room_type.open_rooms == 0, ErrorCode::RoomTypeInUse);
+
+    let hotel = &mut ctx.accounts.hotel;
+    hotel.open_room_types = hotel.open_room_types.saturating_sub(1);
+
+    emit!(RoomTypeClosed {
+        hotel: hotel.key(),
+        room_type: room_type.key(),
+        type_id: room_type.type_id,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RoomTypeCreated {
+    pub hotel: Pubkey,
+    pub room_type: Pubkey,
+    pub type_id: u16,
+    pub name: String,
+    pub capacity: u16,
+    pub amenities: u32,
+    pub security_deposit: u64,
+    pub damage_claim_window_days: u16,
+}
+
+#[event]
+pub struct RoomTypeUpdated {
+    pub hotel: Pubkey,
+    pub room_type: Pubkey,
+    pub name: String,
+    pub capacity: u16,
+    pub amenities: u32,
+    pub security_deposit: u64,
+    pub damage_claim_window_days: u16,
+}
+
+#[event]
+pub struct RoomCreated {
+    pub hotel: Pubkey,
+    pub room: Pubkey,
+    pub room_number: u16,
+    pub room_type: Pubkey,
+}
+
+#[event]
+pub struct RoomClosed {
+    pub hotel: Pubkey,
+    pub room: Pubkey,
+    pub room_number: u16,
+}
+
+#[event]
+pub struct RoomTypeClosed {
+    pub hotel: Pubkey,
+    pub room_type: Pubkey,
+    pub type_id: u16,
+}
\ No newline at end of file
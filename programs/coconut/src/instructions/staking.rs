@@ -3,27 +3,42 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface;
 use crate::context::*;
-use crate::state::*;
 use crate::errors::ErrorCode;
 
 pub fn stake_coco_tokens(ctx: Context<StakeCocoTokens>, amount: u64) -> Result<()> {
+    let distributor = &mut ctx.accounts.revenue_distributor;
     let stake_account = &mut ctx.accounts.stake_account;
-    
-    // Transfer tokens from user to stake account
-    token_interface::transfer(
+
+    if stake_account.owner == Pubkey::default() {
+        stake_account.owner = ctx.accounts.staker.key();
+        stake_account.bump = ctx.bumps.stake_account;
+    }
+
+    // Bank revenue earned at the old stake before the new tokens start earning
+    distributor
+        .rewards
+        .settle(stake_account.staked_amount, stake_account.reward_debt, &mut stake_account.pending_rewards)
+        .ok_or(ErrorCode::Overflow)?;
+
+    // Transfer tokens from user to the stake vault
+    token_interface::transfer_checked(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
-            token_interface::Transfer {
+            token_interface::TransferChecked {
                 from: ctx.accounts.token_account.to_account_info(),
-                to: stake_account.to_account_info(),
+                mint: ctx.accounts.coco_mint.to_account_info(),
+                to: ctx.accounts.stake_vault.to_account_info(),
                 authority: ctx.accounts.staker.to_account_info(),
             },
         ),
         amount,
+        ctx.accounts.coco_mint.decimals,
     )?;
 
     stake_account.staked_amount = stake_account.staked_amount.checked_add(amount).ok_or(ErrorCode::Overflow)?;
     stake_account.last_stake_timestamp = Clock::get()?.unix_timestamp;
+    stake_account.reward_debt = distributor.rewards.accrued(stake_account.staked_amount).ok_or(ErrorCode::Overflow)?;
+    distributor.rewards.total_deposited = distributor.rewards.total_deposited.checked_add(amount).ok_or(ErrorCode::Overflow)?;
 
     emit!(TokensStaked {
         user: ctx.accounts.staker.key(),
@@ -34,58 +49,48 @@ pub fn stake_coco_tokens(ctx: Context<StakeCocoTokens>, amount: u64) -> Result<(
 }
 
 pub fn unstake_coco_tokens(ctx: Context<UnstakeCocoTokens>, amount: u64) -> Result<()> {
+    let distributor = &mut ctx.accounts.revenue_distributor;
     let stake_account = &mut ctx.accounts.stake_account;
     require!(stake_account.staked_amount >= amount, ErrorCode::InsufficientStakedAmount);
-    
-    // Calculate rewards
-    let rewards = calculate_rewards(stake_account);
+
+    // Bank revenue earned so far; it stays claimable after unstaking
+    distributor
+        .rewards
+        .settle(stake_account.staked_amount, stake_account.reward_debt, &mut stake_account.pending_rewards)
+        .ok_or(ErrorCode::Overflow)?;
+
+    let staker_key = ctx.accounts.staker.key();
+    let stake_seeds: &[&[u8]] = &[b"stake", staker_key.as_ref(), &[stake_account.bump]];
 
     // Transfer staked tokens back to user
-    token_interface::transfer(
-        CpiContext::new(
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
-            token_interface::Transfer {
-                from: stake_account.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.stake_vault.to_account_info(),
+                mint: ctx.accounts.coco_mint.to_account_info(),
                 to: ctx.accounts.token_account.to_account_info(),
                 authority: stake_account.to_account_info(),
             },
+            &[stake_seeds],
         ),
         amount,
-    )?;
-
-    // Transfer rewards to user (assuming rewards are in the same token)
-    token_interface::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token_interface::Transfer {
-                from: stake_account.to_account_info(),
-                to: ctx.accounts.token_account.to_account_info(),
-                authority: stake_account.to_account_info(),
-            },
-        ),
-        rewards,
+        ctx.accounts.coco_mint.decimals,
     )?;
 
     stake_account.staked_amount = stake_account.staked_amount.checked_sub(amount).ok_or(ErrorCode::InsufficientStakedAmount)?;
     stake_account.last_stake_timestamp = Clock::get()?.unix_timestamp;
+    stake_account.reward_debt = distributor.rewards.accrued(stake_account.staked_amount).ok_or(ErrorCode::Overflow)?;
+    distributor.rewards.total_deposited = distributor.rewards.total_deposited.checked_sub(amount).ok_or(ErrorCode::Overflow)?;
 
     emit!(TokensUnstaked {
         user: ctx.accounts.staker.key(),
         amount,
-        rewards,
     });
 
     Ok(())
 }
 
-fn calculate_rewards(stake_account: &StakeAccount) -> u64 {
-    let current_time = Clock::get().unwrap().unix_timestamp;
-    let time_staked = (current_time - stake_account.last_stake_timestamp) as u64;
-    
-    // This is a simplified reward calculation. You might want to implement a more sophisticated model.
-    (stake_account.staked_amount * time_staked) / 1_000_000_000 // 1 token per billion seconds per staked token
-}
-
 #[event]
 pub struct TokensStaked {
     pub user: Pubkey,
@@ -96,5 +101,4 @@ pub struct TokensStaked {
 pub struct TokensUnstaked {
     pub user: Pubkey,
     pub amount: u64,
-    pub rewards: u64,
 }
\ No newline at end of file
@@ -6,20 +6,98 @@ use crate::context::*;
 use crate::state::*;
 use crate::errors::ErrorCode;
 
+/// Creates the COCO Token-2022 mint with the `CocoMint` PDA as its mint authority, so tokens
+/// can only be issued through this program. The supply cap and emission schedule can't be
+/// changed afterwards.
+pub fn initialize_coco_mint(
+    ctx: Context<InitializeCocoMint>,
+    decimals: u8,
+    max_supply: u64,
+    epoch_duration: i64,
+    epoch_allowance: u64,
+) -> Result<()> {
+    require!(
+        max_supply > 0 && epoch_duration > 0 && epoch_allowance > 0,
+        ErrorCode::InvalidEmissionSchedule
+    );
+
+    let coco_mint = &mut ctx.accounts.coco_mint;
+    coco_mint.authority = ctx.accounts.admin.key();
+    coco_mint.pending_authority = None;
+    coco_mint.mint = ctx.accounts.coco_token_mint.key();
+    coco_mint.total_supply = 0;
+    coco_mint.max_supply = max_supply;
+    coco_mint.epoch_duration = epoch_duration;
+    coco_mint.epoch_allowance = epoch_allowance;
+    coco_mint.current_epoch_start = Clock::get()?.unix_timestamp;
+    coco_mint.minted_this_epoch = 0;
+    coco_mint.bump = ctx.bumps.coco_mint;
+
+    emit!(CocoMintInitialized {
+        coco_mint: coco_mint.key(),
+        mint: coco_mint.mint,
+        authority: coco_mint.authority,
+        decimals,
+        max_supply,
+        epoch_duration,
+        epoch_allowance,
+    });
+
+    Ok(())
+}
+
+/// Records a verifier's appraisal of a verified hotel, setting how much COCO can be issued
+/// against it. A lower appraisal doesn't affect COCO already issued but blocks further issuance.
+pub fn attest_hotel_appraisal(ctx: Context<AttestHotelAppraisal>, appraised_value: u64) -> Result<()> {
+    let hotel = &mut ctx.accounts.hotel;
+    require!(hotel.is_verified, ErrorCode::HotelNotVerified);
+    require!(appraised_value > 0, ErrorCode::InvalidAppraisal);
+
+    let collateral = &mut ctx.accounts.hotel_collateral;
+    if collateral.hotel == Pubkey::default() {
+        collateral.hotel = hotel.key();
+        collateral.issued_amount = 0;
+        collateral.bump = ctx.bumps.hotel_collateral;
+        hotel.has_collateral = true;
+    }
+    collateral.appraised_value = appraised_value;
+    collateral.appraiser = ctx.accounts.verifier.key();
+    collateral.appraised_at = Clock::get()?.unix_timestamp;
+
+    emit!(HotelAppraised {
+        hotel: collateral.hotel,
+        appraiser: collateral.appraiser,
+        appraised_value,
+    });
+
+    Ok(())
+}
+
+/// Issues COCO backed by a verified hotel, keeping the COCO outstanding against it within the
+/// protocol's loan-to-value limit.
 pub fn issue_coco_tokens(ctx: Context<IssueCococTokens>, amount: u64) -> Result<()> {
     let coco_mint = &mut ctx.accounts.coco_mint;
-    require!(ctx.accounts.authority.key() == coco_mint.authority, ErrorCode::Unauthorized);
+    let collateral = &mut ctx.accounts.hotel_collateral;
+    record_issuance(
+        coco_mint,
+        collateral,
+        &ctx.accounts.hotel,
+        ctx.accounts.protocol_config.max_ltv_bps,
+        amount,
+        Clock::get()?.unix_timestamp,
+    )?;
 
-    coco_mint.total_supply = coco_mint.total_supply.checked_add(amount).ok_or(ErrorCode::Overflow)?;
+    let coco_mint_seeds: &[&[u8]] = &[b"coco_mint", &[coco_mint.bump]];
 
     token_interface::mint_to(
-        CpiContext::new(
+        CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             token_interface::MintTo {
                 mint: ctx.accounts.coco_token_mint.to_account_info(),
                 to: ctx.accounts.token_account.to_account_info(),
-                authority: ctx.accounts.authority.to_account_info(),
+                authority: coco_mint.to_account_info(),
             },
+            &[coco_mint_seeds],
         ),
         amount,
     )?;
@@ -27,13 +105,234 @@ pub fn issue_coco_tokens(ctx: Context<IssueCococTokens>, amount: u64) -> Result<
     emit!(CocoTokensIssued {
         amount,
         recipient: ctx.accounts.token_account.key(),
+        total_supply: coco_mint.total_supply,
+        minted_this_epoch: coco_mint.minted_this_epoch,
+        hotel: collateral.hotel,
+        hotel_issued_amount: collateral.issued_amount,
+    });
+
+    Ok(())
+}
+
+/// Checks an issuance of `amount` against the hotel's loan-to-value limit, the supply cap and
+/// the current epoch's allowance, and records it.
+pub(crate) fn record_issuance(
+    coco_mint: &mut CocoMint,
+    collateral: &mut HotelCollateral,
+    hotel: &Hotel,
+    max_ltv_bps: u16,
+    amount: u64,
+    now: i64,
+) -> Result<()> {
+    require!(hotel.is_verified, ErrorCode::HotelNotVerified);
+
+    let issued_amount = collateral.issued_amount.checked_add(amount).ok_or(ErrorCode::Overflow)?;
+    let issuance_limit = collateral.issuance_limit(max_ltv_bps).ok_or(ErrorCode::Overflow)?;
+    require!(issued_amount <= issuance_limit, ErrorCode::CollateralLimitExceeded);
+
+    coco_mint.record_issuance(amount, now)?;
+    collateral.issued_amount = issued_amount;
+
+    Ok(())
+}
+
+/// Proposes handing the right to issue COCO to `new_authority`, e.g. a multisig signer. It
+/// takes effect once the new authority accepts.
+pub fn propose_coco_mint_authority(ctx: Context<UpdateCocoMint>, new_authority: Pubkey) -> Result<()> {
+    let coco_mint = &mut ctx.accounts.coco_mint;
+    coco_mint.pending_authority = Some(new_authority);
+
+    emit!(CocoMintAuthorityProposed {
+        coco_mint: coco_mint.key(),
+        authority: coco_mint.authority,
+        pending_authority: new_authority,
+    });
+
+    Ok(())
+}
+
+pub fn accept_coco_mint_authority(ctx: Context<AcceptCocoMintAuthority>) -> Result<()> {
+    let coco_mint = &mut ctx.accounts.coco_mint;
+    let previous_authority = coco_mint.authority;
+    coco_mint.authority = ctx.accounts.new_authority.key();
+    coco_mint.pending_authority = None;
+
+    emit!(CocoMintAuthorityUpdated {
+        coco_mint: coco_mint.key(),
+        previous_authority,
+        new_authority: coco_mint.authority,
+    });
+
+    Ok(())
+}
+
+/// Burns COCO from the holder's account. Passing a hotel's collateral account counts the burn
+/// as repayment of COCO issued against that hotel, freeing its issuance capacity; only the
+/// hotel owner or the COCO mint authority can do so.
+pub fn burn_coco_tokens(ctx: Context<BurnCocoTokens>, amount: u64) -> Result<()> {
+    if let Some(collateral) = ctx.accounts.hotel_collateral.as_mut() {
+        let holder = ctx.accounts.holder.key();
+        let hotel_owner = ctx
+            .accounts
+            .hotel
+            .as_ref()
+            .filter(|hotel| hotel.key() == collateral.hotel)
+            .map(|hotel| hotel.owner);
+        require!(
+            holder == ctx.accounts.coco_mint.authority || hotel_owner == Some(holder),
+            ErrorCode::Unauthorized
+        );
+
+        collateral.issued_amount = collateral
+            .issued_amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::RepaymentExceedsIssued)?;
+    }
+
+    token_interface::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::Burn {
+                mint: ctx.accounts.coco_token_mint.to_account_info(),
+                from: ctx.accounts.holder_token_account.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let coco_mint = &mut ctx.accounts.coco_mint;
+    coco_mint.total_supply = coco_mint.total_supply.checked_sub(amount).ok_or(ErrorCode::Overflow)?;
+
+    emit!(CocoTokensBurned {
+        holder: ctx.accounts.holder.key(),
+        amount,
+        redeemed: 0,
+        hotel: ctx.accounts.hotel_collateral.as_ref().map(|collateral| collateral.hotel),
+        total_supply: coco_mint.total_supply,
+    });
+
+    Ok(())
+}
+
+pub fn initialize_redemption_reserve(ctx: Context<InitializeRedemptionReserve>) -> Result<()> {
+    emit!(RedemptionReserveInitialized {
+        reserve: ctx.accounts.redemption_reserve.key(),
+        mint: ctx.accounts.redemption_mint.key(),
+    });
+
+    Ok(())
+}
+
+/// Burns COCO in exchange for a pro-rata share of the redemption reserve, rounded down.
+pub fn redeem_coco_tokens(ctx: Context<RedeemCocoTokens>, amount: u64) -> Result<()> {
+    let coco_mint = &mut ctx.accounts.coco_mint;
+    require!(amount <= coco_mint.total_supply, ErrorCode::InsufficientFunds);
+
+    let redeemed = u64::try_from(
+        ctx.accounts.redemption_reserve.amount as u128 * amount as u128 / coco_mint.total_supply as u128,
+    )
+    .map_err(|_| ErrorCode::Overflow)?;
+    require!(redeemed > 0, ErrorCode::RedemptionTooSmall);
+
+    token_interface::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::Burn {
+                mint: ctx.accounts.coco_token_mint.to_account_info(),
+                from: ctx.accounts.holder_token_account.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let coco_mint_seeds: &[&[u8]] = &[b"coco_mint", &[coco_mint.bump]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.redemption_token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.redemption_reserve.to_account_info(),
+                mint: ctx.accounts.redemption_mint.to_account_info(),
+                to: ctx.accounts.holder_redemption_account.to_account_info(),
+                authority: coco_mint.to_account_info(),
+            },
+            &[coco_mint_seeds],
+        ),
+        redeemed,
+        ctx.accounts.redemption_mint.decimals,
+    )?;
+
+    coco_mint.total_supply -= amount;
+
+    emit!(CocoTokensBurned {
+        holder: ctx.accounts.holder.key(),
+        amount,
+        redeemed,
+        hotel: None,
+        total_supply: coco_mint.total_supply,
     });
 
     Ok(())
 }
 
+#[event]
+pub struct CocoMintInitialized {
+    pub coco_mint: Pubkey,
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub decimals: u8,
+    pub max_supply: u64,
+    pub epoch_duration: i64,
+    pub epoch_allowance: u64,
+}
+
 #[event]
 pub struct CocoTokensIssued {
     pub amount: u64,
     pub recipient: Pubkey,
+    pub total_supply: u64,
+    pub minted_this_epoch: u64,
+    pub hotel: Pubkey,
+    /// COCO outstanding against `hotel` after this issuance
+    pub hotel_issued_amount: u64,
+}
+
+#[event]
+pub struct CocoMintAuthorityProposed {
+    pub coco_mint: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct CocoMintAuthorityUpdated {
+    pub coco_mint: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct HotelAppraised {
+    pub hotel: Pubkey,
+    pub appraiser: Pubkey,
+    pub appraised_value: u64,
+}
+
+#[event]
+pub struct CocoTokensBurned {
+    pub holder: Pubkey,
+    pub amount: u64,
+    /// Redemption reserve funds paid out for the burn; zero for plain burns
+    pub redeemed: u64,
+    /// Hotel whose outstanding COCO the burn repaid, if any
+    pub hotel: Option<Pubkey>,
+    pub total_supply: u64,
+}
+
+#[event]
+pub struct RedemptionReserveInitialized {
+    pub reserve: Pubkey,
+    pub mint: Pubkey,
 }
\ No newline at end of file
@@ -0,0 +1,228 @@
+// File: instructions/vesting.rs
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use crate::context::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::instructions::token::{record_issuance, CocoTokensIssued};
+
+/// Locks `params.total_amount` COCO from the creator in a vault released to the beneficiary
+/// over the schedule. `cliff` and `duration` are in seconds after `start`.
+pub fn create_vesting(ctx: Context<CreateVesting>, params: VestingParams) -> Result<()> {
+    init_vesting(&mut ctx.accounts.vesting, ctx.accounts.creator.key(), &params, ctx.bumps.vesting)?;
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.creator_token_account.to_account_info(),
+                mint: ctx.accounts.coco_token_mint.to_account_info(),
+                to: ctx.accounts.vesting_vault.to_account_info(),
+                authority: ctx.accounts.creator.to_account_info(),
+            },
+        ),
+        params.total_amount,
+        ctx.accounts.coco_token_mint.decimals,
+    )?;
+
+    Ok(())
+}
+
+/// Issues newly minted COCO straight into a vesting vault, so allocations are locked from the
+/// moment they exist. Subject to the same collateral, supply and epoch limits as
+/// `issue_coco_tokens`.
+pub fn issue_vested_coco_tokens(ctx: Context<IssueVestedCocoTokens>, params: VestingParams) -> Result<()> {
+    init_vesting(&mut ctx.accounts.vesting, ctx.accounts.authority.key(), &params, ctx.bumps.vesting)?;
+
+    let coco_mint = &mut ctx.accounts.coco_mint;
+    let collateral = &mut ctx.accounts.hotel_collateral;
+    record_issuance(
+        coco_mint,
+        collateral,
+        &ctx.accounts.hotel,
+        ctx.accounts.protocol_config.max_ltv_bps,
+        params.total_amount,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    let coco_mint_seeds: &[&[u8]] = &[b"coco_mint", &[coco_mint.bump]];
+
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::MintTo {
+                mint: ctx.accounts.coco_token_mint.to_account_info(),
+                to: ctx.accounts.vesting_vault.to_account_info(),
+                authority: coco_mint.to_account_info(),
+            },
+            &[coco_mint_seeds],
+        ),
+        params.total_amount,
+    )?;
+
+    emit!(CocoTokensIssued {
+        amount: params.total_amount,
+        recipient: ctx.accounts.vesting_vault.key(),
+        total_supply: coco_mint.total_supply,
+        minted_this_epoch: coco_mint.minted_this_epoch,
+        hotel: collateral.hotel,
+        hotel_issued_amount: collateral.issued_amount,
+    });
+
+    Ok(())
+}
+
+pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+    let vesting = &mut ctx.accounts.vesting;
+    let vested = vesting.vested_amount(Clock::get()?.unix_timestamp);
+    let amount = vested - vesting.claimed_amount;
+    require!(amount > 0, ErrorCode::NothingToClaim);
+
+    let vesting_id_bytes = vesting.vesting_id.to_le_bytes();
+    let vesting_seeds: &[&[u8]] = &[
+        b"vesting",
+        vesting.beneficiary.as_ref(),
+        vesting_id_bytes.as_ref(),
+        &[vesting.bump],
+    ];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.vesting_vault.to_account_info(),
+                mint: ctx.accounts.coco_token_mint.to_account_info(),
+                to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                authority: vesting.to_account_info(),
+            },
+            &[vesting_seeds],
+        ),
+        amount,
+        ctx.accounts.coco_token_mint.decimals,
+    )?;
+
+    vesting.claimed_amount = vested;
+
+    emit!(VestedTokensClaimed {
+        vesting: vesting.key(),
+        beneficiary: vesting.beneficiary,
+        amount,
+        claimed_amount: vested,
+    });
+
+    Ok(())
+}
+
+/// Lets the protocol admin stop a revocable schedule, returning the still-locked COCO to the
+/// grant's creator. Whatever had vested by now stays claimable by the beneficiary.
+pub fn revoke_vesting(ctx: Context<RevokeVesting>) -> Result<()> {
+    let vesting = &mut ctx.accounts.vesting;
+    require!(vesting.revocable, ErrorCode::VestingNotRevocable);
+    require!(vesting.revoked_at.is_none(), ErrorCode::VestingAlreadyRevoked);
+
+    let now = Clock::get()?.unix_timestamp;
+    let unvested = vesting.total_amount - vesting.vested_amount(now);
+    vesting.revoked_at = Some(now);
+
+    if unvested > 0 {
+        let vesting_id_bytes = vesting.vesting_id.to_le_bytes();
+        let vesting_seeds: &[&[u8]] = &[
+            b"vesting",
+            vesting.beneficiary.as_ref(),
+            vesting_id_bytes.as_ref(),
+            &[vesting.bump],
+        ];
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.vesting_vault.to_account_info(),
+                    mint: ctx.accounts.coco_token_mint.to_account_info(),
+                    to: ctx.accounts.creator_token_account.to_account_info(),
+                    authority: vesting.to_account_info(),
+                },
+                &[vesting_seeds],
+            ),
+            unvested,
+            ctx.accounts.coco_token_mint.decimals,
+        )?;
+    }
+
+    emit!(VestingRevoked {
+        vesting: vesting.key(),
+        creator: vesting.creator,
+        beneficiary: vesting.beneficiary,
+        unvested,
+    });
+
+    Ok(())
+}
+
+fn init_vesting(vesting: &mut Account<Vesting>, creator: Pubkey, params: &VestingParams, bump: u8) -> Result<()> {
+    require!(
+        params.total_amount > 0 && params.duration > 0 && params.cliff >= 0 && params.cliff <= params.duration,
+        ErrorCode::InvalidVestingSchedule
+    );
+    // Keeps `now - start` in `vested_amount` from overflowing
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        (now - MAX_VESTING_START_OFFSET..=now + MAX_VESTING_START_OFFSET).contains(&params.start),
+        ErrorCode::InvalidVestingSchedule
+    );
+
+    vesting.creator = creator;
+    vesting.beneficiary = params.beneficiary;
+    vesting.vesting_id = params.vesting_id;
+    vesting.total_amount = params.total_amount;
+    vesting.claimed_amount = 0;
+    vesting.start = params.start;
+    vesting.cliff = params.cliff;
+    vesting.duration = params.duration;
+    vesting.revocable = params.revocable;
+    vesting.revoked_at = None;
+    vesting.bump = bump;
+
+    emit!(VestingCreated {
+        vesting: vesting.key(),
+        creator,
+        beneficiary: params.beneficiary,
+        total_amount: params.total_amount,
+        start: params.start,
+        cliff: params.cliff,
+        duration: params.duration,
+        revocable: params.revocable,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VestingCreated {
+    pub vesting: Pubkey,
+    pub creator: Pubkey,
+    pub beneficiary: Pubkey,
+    pub total_amount: u64,
+    pub start: i64,
+    pub cliff: i64,
+    pub duration: i64,
+    pub revocable: bool,
+}
+
+#[event]
+pub struct VestedTokensClaimed {
+    pub vesting: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub claimed_amount: u64,
+}
+
+#[event]
+pub struct VestingRevoked {
+    pub vesting: Pubkey,
+    /// Received the unvested COCO
+    pub creator: Pubkey,
+    pub beneficiary: Pubkey,
+    pub unvested: u64,
+}
\ No newline at end of file
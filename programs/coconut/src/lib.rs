@@ -6,8 +6,10 @@ pub mod state;
 pub mod context;
 pub mod instructions;
 pub mod error;
+pub mod nft;
 
 use instructions::*;
+use state::*;
 
 declare_id!("FSudCsBKGDQShx9orrZHWTq7pXF14NnPqw6MLxC47uXM");
 
@@ -15,20 +17,185 @@ declare_id!("FSudCsBKGDQShx9orrZHWTq7pXF14NnPqw6MLxC47uXM");
 pub mod coconut_rwa {
     use super::*;
 
-    pub fn initialize_hotel(ctx: Context<InitializeHotel>, name: String, room_count: u16) -> Result<()> {
-        hotel::initialize_hotel(ctx, name, room_count)
+    pub fn initialize_hotel_registry(ctx: Context<InitializeHotelRegistry>) -> Result<()> {
+        hotel::initialize_hotel_registry(ctx)
+    }
+
+    pub fn initialize_hotel(ctx: Context<InitializeHotel>, hotel_id: u64, name: String, room_count: u16) -> Result<()> {
+        hotel::initialize_hotel(ctx, hotel_id, name, room_count)
+    }
+
+    pub fn update_hotel(
+        ctx: Context<UpdateHotel>,
+        name: Option<String>,
+        room_count: Option<u16>,
+        transferable_receipts: Option<bool>,
+        resale_royalty_bps: Option<u16>,
+    ) -> Result<()> {
+        hotel::update_hotel(ctx, name, room_count, transferable_receipts, resale_royalty_bps)
+    }
+
+    pub fn propose_hotel_owner(ctx: Context<ProposeHotelOwner>, new_owner: Pubkey) -> Result<()> {
+        hotel::propose_hotel_owner(ctx, new_owner)
+    }
+
+    pub fn accept_hotel_owner(ctx: Context<AcceptHotelOwner>) -> Result<()> {
+        hotel::accept_hotel_owner(ctx)
+    }
+
+    pub fn close_hotel(ctx: Context<CloseHotel>) -> Result<()> {
+        hotel::close_hotel(ctx)
     }
 
     pub fn verify_hotel(ctx: Context<VerifyHotel>) -> Result<()> {
         hotel::verify_hotel(ctx)
     }
 
+    pub fn unverify_hotel(ctx: Context<UnverifyHotel>) -> Result<()> {
+        hotel::unverify_hotel(ctx)
+    }
+
+    pub fn initialize_protocol_config(ctx: Context<InitializeProtocolConfig>) -> Result<()> {
+        protocol_config::initialize_protocol_config(ctx)
+    }
+
+    pub fn add_verifier(ctx: Context<ManageVerifiers>, verifier: Pubkey) -> Result<()> {
+        protocol_config::add_verifier(ctx, verifier)
+    }
+
+    pub fn remove_verifier(ctx: Context<ManageVerifiers>, verifier: Pubkey) -> Result<()> {
+        protocol_config::remove_verifier(ctx, verifier)
+    }
+
+    pub fn propose_protocol_admin(ctx: Context<UpdateProtocolConfig>, new_admin: Pubkey) -> Result<()> {
+        protocol_config::propose_protocol_admin(ctx, new_admin)
+    }
+
+    pub fn accept_protocol_admin(ctx: Context<AcceptProtocolAdmin>) -> Result<()> {
+        protocol_config::accept_protocol_admin(ctx)
+    }
+
+    pub fn set_protocol_fee(ctx: Context<UpdateProtocolConfig>, protocol_fee_bps: u16) -> Result<()> {
+        protocol_config::set_protocol_fee(ctx, protocol_fee_bps)
+    }
+
+    pub fn set_revenue_share(ctx: Context<UpdateProtocolConfig>, revenue_share_bps: u16) -> Result<()> {
+        protocol_config::set_revenue_share(ctx, revenue_share_bps)
+    }
+
+    pub fn set_max_ltv(ctx: Context<UpdateProtocolConfig>, max_ltv_bps: u16) -> Result<()> {
+        protocol_config::set_max_ltv(ctx, max_ltv_bps)
+    }
+
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        protocol_config::initialize_treasury(ctx)
+    }
+
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        protocol_config::withdraw_treasury(ctx, amount)
+    }
+
+    pub fn create_hotel_shares(
+        ctx: Context<CreateHotelShares>,
+        decimals: u8,
+        max_supply: u64,
+        income_share_bps: u16,
+    ) -> Result<()> {
+        hotel_shares::create_hotel_shares(ctx, decimals, max_supply, income_share_bps)
+    }
+
+    pub fn issue_hotel_shares(ctx: Context<IssueHotelShares>, amount: u64) -> Result<()> {
+        hotel_shares::issue_hotel_shares(ctx, amount)
+    }
+
+    pub fn deposit_hotel_shares(ctx: Context<DepositHotelShares>, amount: u64) -> Result<()> {
+        hotel_shares::deposit_hotel_shares(ctx, amount)
+    }
+
+    pub fn withdraw_hotel_shares(ctx: Context<WithdrawHotelShares>, amount: u64) -> Result<()> {
+        hotel_shares::withdraw_hotel_shares(ctx, amount)
+    }
+
+    pub fn claim_hotel_income(ctx: Context<ClaimHotelIncome>) -> Result<()> {
+        hotel_shares::claim_hotel_income(ctx)
+    }
+
+    pub fn create_room_type(ctx: Context<CreateRoomType>, params: RoomTypeParams) -> Result<()> {
+        room::create_room_type(ctx, params)
+    }
+
+    pub fn update_room_type(ctx: Context<UpdateRoomType>, update: RoomTypeUpdate) -> Result<()> {
+        room::update_room_type(ctx, update)
+    }
+
+    pub fn create_room(ctx: Context<CreateRoom>, room_number: u16) -> Result<()> {
+        room::create_room(ctx, room_number)
+    }
+
+    pub fn close_room(ctx: Context<CloseRoom>) -> Result<()> {
+        room::close_room(ctx)
+    }
+
+    pub fn close_room_type(ctx: Context<CloseRoomType>) -> Result<()> {
+        room::close_room_type(ctx)
+    }
+
+    pub fn initialize_coco_mint(
+        ctx: Context<InitializeCocoMint>,
+        decimals: u8,
+        max_supply: u64,
+        epoch_duration: i64,
+        epoch_allowance: u64,
+    ) -> Result<()> {
+        token::initialize_coco_mint(ctx, decimals, max_supply, epoch_duration, epoch_allowance)
+    }
+
+    pub fn attest_hotel_appraisal(ctx: Context<AttestHotelAppraisal>, appraised_value: u64) -> Result<()> {
+        token::attest_hotel_appraisal(ctx, appraised_value)
+    }
+
     pub fn issue_coco_tokens(ctx: Context<IssueCococTokens>, amount: u64) -> Result<()> {
         token::issue_coco_tokens(ctx, amount)
     }
 
-    pub fn create_liquidity_pool(ctx: Context<CreateLiquidityPool>, initial_liquidity: u64) -> Result<()> {
-        liquidity_pool::create_liquidity_pool(ctx, initial_liquidity)
+    pub fn propose_coco_mint_authority(ctx: Context<UpdateCocoMint>, new_authority: Pubkey) -> Result<()> {
+        token::propose_coco_mint_authority(ctx, new_authority)
+    }
+
+    pub fn accept_coco_mint_authority(ctx: Context<AcceptCocoMintAuthority>) -> Result<()> {
+        token::accept_coco_mint_authority(ctx)
+    }
+
+    pub fn burn_coco_tokens(ctx: Context<BurnCocoTokens>, amount: u64) -> Result<()> {
+        token::burn_coco_tokens(ctx, amount)
+    }
+
+    pub fn initialize_redemption_reserve(ctx: Context<InitializeRedemptionReserve>) -> Result<()> {
+        token::initialize_redemption_reserve(ctx)
+    }
+
+    pub fn redeem_coco_tokens(ctx: Context<RedeemCocoTokens>, amount: u64) -> Result<()> {
+        token::redeem_coco_tokens(ctx, amount)
+    }
+
+    pub fn create_vesting(ctx: Context<CreateVesting>, params: VestingParams) -> Result<()> {
+        vesting::create_vesting(ctx, params)
+    }
+
+    pub fn issue_vested_coco_tokens(ctx: Context<IssueVestedCocoTokens>, params: VestingParams) -> Result<()> {
+        vesting::issue_vested_coco_tokens(ctx, params)
+    }
+
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        vesting::claim_vested(ctx)
+    }
+
+    pub fn revoke_vesting(ctx: Context<RevokeVesting>) -> Result<()> {
+        vesting::revoke_vesting(ctx)
+    }
+
+    pub fn create_liquidity_pool(ctx: Context<CreateLiquidityPool>, initial_liquidity: u64, twap_window: i64) -> Result<()> {
+        liquidity_pool::create_liquidity_pool(ctx, initial_liquidity, twap_window)
     }
 
     pub fn add_liquidity(ctx: Context<AddLiquidity>, coco_amount: u64, usdc_amount: u64) -> Result<()> {
@@ -39,6 +206,51 @@ pub mod coconut_rwa {
         liquidity_pool::swap_tokens(ctx, amount_in, min_amount_out)
     }
 
+    pub fn sync_pool_price(ctx: Context<SyncPoolPrice>) -> Result<()> {
+        liquidity_pool::sync_pool_price(ctx)
+    }
+
+    pub fn set_pool_twap_window(ctx: Context<UpdateLiquidityPool>, twap_window: i64) -> Result<()> {
+        liquidity_pool::set_pool_twap_window(ctx, twap_window)
+    }
+
+    pub fn propose_pool_authority(ctx: Context<UpdateLiquidityPool>, new_authority: Pubkey) -> Result<()> {
+        liquidity_pool::propose_pool_authority(ctx, new_authority)
+    }
+
+    pub fn accept_pool_authority(ctx: Context<AcceptPoolAuthority>) -> Result<()> {
+        liquidity_pool::accept_pool_authority(ctx)
+    }
+
+    pub fn create_multisig(ctx: Context<CreateMultisig>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        multisig::create_multisig(ctx, members, threshold)
+    }
+
+    pub fn propose_multisig_transaction(
+        ctx: Context<ProposeMultisigTransaction>,
+        program_id: Pubkey,
+        accounts: Vec<ProposalAccountMeta>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        multisig::propose_multisig_transaction(ctx, program_id, accounts, data)
+    }
+
+    pub fn approve_multisig_transaction(ctx: Context<ApproveMultisigTransaction>) -> Result<()> {
+        multisig::approve_multisig_transaction(ctx)
+    }
+
+    pub fn execute_multisig_transaction(ctx: Context<ExecuteMultisigTransaction>) -> Result<()> {
+        multisig::execute_multisig_transaction(ctx)
+    }
+
+    pub fn close_multisig_proposal(ctx: Context<CloseMultisigProposal>) -> Result<()> {
+        multisig::close_multisig_proposal(ctx)
+    }
+
+    pub fn set_multisig_members(ctx: Context<SetMultisigMembers>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        multisig::set_multisig_members(ctx, members, threshold)
+    }
+
     pub fn stake_coco_tokens(ctx: Context<StakeCocoTokens>, amount: u64) -> Result<()> {
         staking::stake_coco_tokens(ctx, amount)
     }
@@ -47,14 +259,99 @@ pub mod coconut_rwa {
         staking::unstake_coco_tokens(ctx, amount)
     }
 
-    pub fn rent_room(ctx: Context<RentRoom>, room_number: u16, duration: i64, usdc_amount: u64) -> Result<()> {
-        rental::rent_room(ctx, room_number, duration, usdc_amount)
+    pub fn initialize_revenue_distributor(ctx: Context<InitializeRevenueDistributor>) -> Result<()> {
+        revenue::initialize_revenue_distributor(ctx)
+    }
+
+    pub fn claim_revenue(ctx: Context<ClaimRevenue>) -> Result<()> {
+        revenue::claim_revenue(ctx)
+    }
+
+    pub fn book_room(ctx: Context<BookRoom>, check_in_day: u32, nights: u16, max_coco_in: u64) -> Result<()> {
+        rental::book_room(ctx, check_in_day, nights, max_coco_in)
+    }
+
+    pub fn check_in(ctx: Context<CheckIn>) -> Result<()> {
+        booking::check_in(ctx)
+    }
+
+    pub fn check_out(ctx: Context<CheckOut>) -> Result<()> {
+        booking::check_out(ctx)
+    }
+
+    pub fn cancel_booking(ctx: Context<CancelBooking>) -> Result<()> {
+        booking::cancel_booking(ctx)
+    }
+
+    pub fn claim_no_show_refund(ctx: Context<ClaimNoShowRefund>) -> Result<()> {
+        booking::claim_no_show_refund(ctx)
+    }
+
+    pub fn release_deposit(ctx: Context<ReleaseDeposit>) -> Result<()> {
+        booking::release_deposit(ctx)
+    }
+
+    pub fn claim_damage(ctx: Context<ClaimDamage>, amount: u64) -> Result<()> {
+        booking::claim_damage(ctx, amount)
+    }
+
+    pub fn resolve_damage_claim(ctx: Context<ResolveDamageClaim>, hotel_amount: u64) -> Result<()> {
+        booking::resolve_damage_claim(ctx, hotel_amount)
+    }
+
+    pub fn submit_review(ctx: Context<SubmitReview>, rating: u8, content_hash: [u8; 32]) -> Result<()> {
+        review::submit_review(ctx, rating, content_hash)
+    }
+
+    pub fn list_booking_for_resale(ctx: Context<ListBookingForResale>, price: u64) -> Result<()> {
+        resale::list_booking_for_resale(ctx, price)
+    }
+
+    pub fn cancel_resale_listing(ctx: Context<CancelResaleListing>) -> Result<()> {
+        resale::cancel_resale_listing(ctx)
+    }
+
+    pub fn buy_resale_booking(ctx: Context<BuyResaleBooking>, max_price: u64) -> Result<()> {
+        resale::buy_resale_booking(ctx, max_price)
     }
 
     pub fn create_rental_listing(ctx: Context<CreateRentalListing>, room_number: u16, price: u64) -> Result<()> {
         rental::create_rental_listing(ctx, room_number, price)
     }
 
+    pub fn update_listing_price(ctx: Context<UpdateRentalListing>, price: u64) -> Result<()> {
+        rental::update_listing_price(ctx, price)
+    }
+
+    pub fn deactivate_listing(ctx: Context<UpdateRentalListing>) -> Result<()> {
+        rental::deactivate_listing(ctx)
+    }
+
+    pub fn reactivate_listing(ctx: Context<UpdateRentalListing>) -> Result<()> {
+        rental::reactivate_listing(ctx)
+    }
+
+    pub fn close_listing(ctx: Context<CloseRentalListing>) -> Result<()> {
+        rental::close_listing(ctx)
+    }
+
+    pub fn set_pricing_rules(
+        ctx: Context<SetPricingRules>,
+        seasonal_rates: Vec<SeasonalRate>,
+        weekend_surcharge_bps: u16,
+        stay_discounts: Vec<StayDiscount>,
+    ) -> Result<()> {
+        pricing::set_pricing_rules(ctx, seasonal_rates, weekend_surcharge_bps, stay_discounts)
+    }
+
+    pub fn clear_pricing_rules(ctx: Context<ClearPricingRules>) -> Result<()> {
+        pricing::clear_pricing_rules(ctx)
+    }
+
+    pub fn quote_booking(ctx: Context<QuoteBooking>, check_in_day: u32, nights: u16) -> Result<u64> {
+        pricing::quote_booking(ctx, check_in_day, nights)
+    }
+
     pub fn confidential_transfer(ctx: Context<ConfidentialTransfer>, encrypted_amount: [u8; 32]) -> Result<()> {
         confidential_transfer::confidential_transfer(ctx, encrypted_amount)
     }
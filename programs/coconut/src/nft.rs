@@ -0,0 +1,402 @@
+// File: nft.rs
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{ExtensionType, StateWithExtensions},
+    instruction::AuthorityType,
+    state::{Account as TokenAccountState, Mint as MintState},
+};
+use anchor_spl::token_interface::{
+    self,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_metadata_interface::state::{Field, TokenMetadata},
+};
+
+/// Token-2022 metadata written into a program-issued NFT mint.
+pub struct NftMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub additional_metadata: Vec<(String, String)>,
+}
+
+/// Accounts involved in creating an NFT mint and minting it to its recipient.
+pub struct MintNftAccounts<'a, 'info> {
+    pub payer: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub recipient: &'a AccountInfo<'info>,
+    pub recipient_token_account: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+/// Accounts involved in burning an NFT and closing its mint.
+pub struct BurnNftAccounts<'a, 'info> {
+    pub mint: &'a AccountInfo<'info>,
+    pub holder_token_account: &'a AccountInfo<'info>,
+    pub rent_destination: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+}
+
+/// Accounts involved in moving an NFT to a new holder's associated token account.
+pub struct TransferNftAccounts<'a, 'info> {
+    pub payer: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub holder_token_account: &'a AccountInfo<'info>,
+    pub recipient: &'a AccountInfo<'info>,
+    pub recipient_token_account: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+/// Creates a Token-2022 mint at a program PDA and mints a single token to the recipient's
+/// associated token account. The mint PDA is its own permanent delegate, close authority,
+/// freeze authority and metadata update authority so the program can later burn the token and
+/// reclaim the mint. The holder's account is frozen, so the token only moves or burns through
+/// the program.
+pub fn mint_nft<'info>(
+    accounts: MintNftAccounts<'_, 'info>,
+    mint_seeds: &[&[u8]],
+    metadata: NftMetadata,
+    non_transferable: bool,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[mint_seeds];
+    let mint_key = accounts.mint.key();
+
+    let mut extensions = vec![
+        ExtensionType::MetadataPointer,
+        ExtensionType::PermanentDelegate,
+        ExtensionType::MintCloseAuthority,
+    ];
+    if non_transferable {
+        extensions.push(ExtensionType::NonTransferable);
+    }
+    let space = ExtensionType::try_calculate_account_len::<MintState>(&extensions)?;
+
+    let token_metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(mint_key))?,
+        mint: mint_key,
+        name: metadata.name.clone(),
+        symbol: metadata.symbol.clone(),
+        uri: metadata.uri.clone(),
+        additional_metadata: metadata.additional_metadata.clone(),
+    };
+    // The metadata extension reallocates the mint, so fund it for the final size up front.
+    let lamports = Rent::get()?.minimum_balance(space + token_metadata.tlv_size_of()?);
+
+    create_mint_account(&accounts, signer_seeds, space, lamports)?;
+
+    token_interface::metadata_pointer_initialize(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            token_interface::MetadataPointerInitialize {
+                token_program_id: accounts.token_program.clone(),
+                mint: accounts.mint.clone(),
+            },
+        ),
+        Some(mint_key),
+        Some(mint_key),
+    )?;
+
+    token_interface::permanent_delegate_initialize(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            token_interface::PermanentDelegateInitialize {
+                token_program_id: accounts.token_program.clone(),
+                mint: accounts.mint.clone(),
+            },
+        ),
+        &mint_key,
+    )?;
+
+    token_interface::mint_close_authority_initialize(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            token_interface::MintCloseAuthorityInitialize {
+                token_program_id: accounts.token_program.clone(),
+                mint: accounts.mint.clone(),
+            },
+        ),
+        Some(&mint_key),
+    )?;
+
+    if non_transferable {
+        token_interface::non_transferable_mint_initialize(CpiContext::new(
+            accounts.token_program.clone(),
+            token_interface::NonTransferableMintInitialize {
+                token_program_id: accounts.token_program.clone(),
+                mint: accounts.mint.clone(),
+            },
+        ))?;
+    }
+
+    token_interface::initialize_mint2(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            token_interface::InitializeMint2 {
+                mint: accounts.mint.clone(),
+            },
+        ),
+        0,
+        &mint_key,
+        Some(&mint_key),
+    )?;
+
+    token_interface::token_metadata_initialize(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            token_interface::TokenMetadataInitialize {
+                token_program_id: accounts.token_program.clone(),
+                metadata: accounts.mint.clone(),
+                update_authority: accounts.mint.clone(),
+                mint_authority: accounts.mint.clone(),
+                mint: accounts.mint.clone(),
+            },
+            signer_seeds,
+        ),
+        metadata.name,
+        metadata.symbol,
+        metadata.uri,
+    )?;
+
+    for (key, value) in metadata.additional_metadata {
+        token_interface::token_metadata_update_field(
+            CpiContext::new_with_signer(
+                accounts.token_program.clone(),
+                token_interface::TokenMetadataUpdateField {
+                    token_program_id: accounts.token_program.clone(),
+                    metadata: accounts.mint.clone(),
+                    update_authority: accounts.mint.clone(),
+                },
+                signer_seeds,
+            ),
+            Field::Key(key),
+            value,
+        )?;
+    }
+
+    associated_token::create_idempotent(CpiContext::new(
+        accounts.associated_token_program.clone(),
+        associated_token::Create {
+            payer: accounts.payer.clone(),
+            associated_token: accounts.recipient_token_account.clone(),
+            authority: accounts.recipient.clone(),
+            mint: accounts.mint.clone(),
+            system_program: accounts.system_program.clone(),
+            token_program: accounts.token_program.clone(),
+        },
+    ))?;
+
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            token_interface::MintTo {
+                mint: accounts.mint.clone(),
+                to: accounts.recipient_token_account.clone(),
+                authority: accounts.mint.clone(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    set_frozen(accounts.token_program, accounts.recipient_token_account, accounts.mint, signer_seeds, true)?;
+
+    // Drop the mint authority so the supply is fixed at one token
+    token_interface::set_authority(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            token_interface::SetAuthority {
+                current_authority: accounts.mint.clone(),
+                account_or_mint: accounts.mint.clone(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    Ok(())
+}
+
+/// Burns an NFT minted by `mint_nft` from its holder using the mint's permanent delegate
+/// authority and closes the mint, returning its rent to `rent_destination`.
+pub fn burn_nft<'info>(accounts: BurnNftAccounts<'_, 'info>, mint_seeds: &[&[u8]]) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[mint_seeds];
+
+    set_frozen(accounts.token_program, accounts.holder_token_account, accounts.mint, signer_seeds, false)?;
+
+    token_interface::burn(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            token_interface::Burn {
+                mint: accounts.mint.clone(),
+                from: accounts.holder_token_account.clone(),
+                authority: accounts.mint.clone(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    token_interface::close_account(CpiContext::new_with_signer(
+        accounts.token_program.clone(),
+        token_interface::CloseAccount {
+            account: accounts.mint.clone(),
+            destination: accounts.rent_destination.clone(),
+            authority: accounts.mint.clone(),
+        },
+        signer_seeds,
+    ))?;
+
+    Ok(())
+}
+
+/// Moves an NFT minted by `mint_nft` to the recipient's associated token account using the
+/// mint's permanent delegate authority, so the current holder doesn't need to sign. The
+/// recipient's account is frozen like the original holder's.
+pub fn transfer_nft<'info>(accounts: TransferNftAccounts<'_, 'info>, mint_seeds: &[&[u8]]) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[mint_seeds];
+
+    associated_token::create_idempotent(CpiContext::new(
+        accounts.associated_token_program.clone(),
+        associated_token::Create {
+            payer: accounts.payer.clone(),
+            associated_token: accounts.recipient_token_account.clone(),
+            authority: accounts.recipient.clone(),
+            mint: accounts.mint.clone(),
+            system_program: accounts.system_program.clone(),
+            token_program: accounts.token_program.clone(),
+        },
+    ))?;
+
+    set_frozen(accounts.token_program, accounts.holder_token_account, accounts.mint, signer_seeds, false)?;
+    set_frozen(accounts.token_program, accounts.recipient_token_account, accounts.mint, signer_seeds, false)?;
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            token_interface::TransferChecked {
+                from: accounts.holder_token_account.clone(),
+                mint: accounts.mint.clone(),
+                to: accounts.recipient_token_account.clone(),
+                authority: accounts.mint.clone(),
+            },
+            signer_seeds,
+        ),
+        1,
+        0,
+    )?;
+
+    set_frozen(accounts.token_program, accounts.recipient_token_account, accounts.mint, signer_seeds, true)?;
+
+    Ok(())
+}
+
+/// Freezes or thaws a holder's account with the mint's freeze authority, if it isn't already
+/// in that state.
+fn set_frozen<'info>(
+    token_program: &AccountInfo<'info>,
+    token_account: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    frozen: bool,
+) -> Result<()> {
+    let is_frozen = {
+        let data = token_account.try_borrow_data()?;
+        StateWithExtensions::<TokenAccountState>::unpack(&data)?.base.is_frozen()
+    };
+
+    if is_frozen == frozen {
+        return Ok(());
+    }
+
+    if frozen {
+        token_interface::freeze_account(CpiContext::new_with_signer(
+            token_program.clone(),
+            token_interface::FreezeAccount {
+                account: token_account.clone(),
+                mint: mint.clone(),
+                authority: mint.clone(),
+            },
+            signer_seeds,
+        ))
+    } else {
+        token_interface::thaw_account(CpiContext::new_with_signer(
+            token_program.clone(),
+            token_interface::ThawAccount {
+                account: token_account.clone(),
+                mint: mint.clone(),
+                authority: mint.clone(),
+            },
+            signer_seeds,
+        ))
+    }
+}
+
+fn create_mint_account<'info>(
+    accounts: &MintNftAccounts<'_, 'info>,
+    signer_seeds: &[&[&[u8]]],
+    space: usize,
+    lamports: u64,
+) -> Result<()> {
+    let current_lamports = accounts.mint.lamports();
+
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                accounts.system_program.clone(),
+                system_program::CreateAccount {
+                    from: accounts.payer.clone(),
+                    to: accounts.mint.clone(),
+                },
+                signer_seeds,
+            ),
+            lamports,
+            space as u64,
+            accounts.token_program.key,
+        );
+    }
+
+    // Someone pre-funded the PDA, so top it up and claim it instead of creating it
+    if lamports > current_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                accounts.system_program.clone(),
+                system_program::Transfer {
+                    from: accounts.payer.clone(),
+                    to: accounts.mint.clone(),
+                },
+            ),
+            lamports - current_lamports,
+        )?;
+    }
+
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            accounts.system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: accounts.mint.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+
+    system_program::assign(
+        CpiContext::new_with_signer(
+            accounts.system_program.clone(),
+            system_program::Assign {
+                account_to_assign: accounts.mint.clone(),
+            },
+            signer_seeds,
+        ),
+        accounts.token_program.key,
+    )?;
+
+    Ok(())
+}
\ No newline at end of file
@@ -1,39 +1,1118 @@
 // File: state.rs
 
 use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+pub const MAX_VERIFIERS: usize = 10;
+pub const MAX_HOTEL_NAME_LEN: usize = 64;
+pub const MAX_ROOM_TYPE_NAME_LEN: usize = 32;
+pub const SECONDS_PER_DAY: i64 = 86_400;
+/// Nights covered by a single `RoomCalendar` bitmap
+pub const CALENDAR_WINDOW_DAYS: u32 = 32;
+/// Longest stay; keeps every booking within two calendar windows
+pub const MAX_STAY_NIGHTS: u16 = 30;
+pub const BASIS_POINTS: u64 = 10_000;
+/// Highest protocol fee the admin can set
+pub const MAX_PROTOCOL_FEE_BPS: u16 = 1_000;
+/// Highest share of booking revenue the admin can route to COCO stakers
+pub const MAX_REVENUE_SHARE_BPS: u16 = 2_000;
+/// Fixed-point scale of `RewardAccumulator::reward_per_share`
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+pub const MIN_RATING: u8 = 1;
+pub const MAX_RATING: u8 = 5;
+/// Longest time a hotel can be given to file a damage claim after check-out
+pub const MAX_DAMAGE_CLAIM_WINDOW_DAYS: u16 = 30;
+pub const MAX_SEASONAL_RATES: usize = 12;
+pub const MAX_STAY_DISCOUNTS: usize = 4;
+pub const MAX_MULTISIG_MEMBERS: usize = 10;
+/// Largest instruction a multisig proposal can carry
+pub const MAX_PROPOSAL_ACCOUNTS: usize = 24;
+pub const MAX_PROPOSAL_DATA_LEN: usize = 512;
+/// Fixed-point scale of pool prices
+pub const PRICE_PRECISION: u128 = 1_000_000_000_000;
+/// Bounds on a pool's TWAP window, in seconds
+pub const MIN_TWAP_WINDOW: i64 = 600;
+pub const MAX_TWAP_WINDOW: i64 = 86_400;
+/// How long COCO must have been staked before it counts toward a discount tier
+pub const MIN_DISCOUNT_STAKE_AGE: i64 = 7 * SECONDS_PER_DAY;
+/// Furthest a vesting schedule can start from the time it's created, in either direction
+pub const MAX_VESTING_START_OFFSET: i64 = 10 * 365 * SECONDS_PER_DAY;
+/// Discounts on COCO payments as (minimum whole COCO staked, discount in basis points),
+/// highest tier first
+pub const STAKER_DISCOUNT_TIERS: [(u64, u16); 3] = [(100_000, 1_000), (10_000, 500), (1_000, 200)];
 
 #[account]
 pub struct Hotel {
     pub owner: Pubkey,
+    /// Original owner; part of the PDA seeds, so it never changes after a transfer
+    pub creator: Pubkey,
+    pub hotel_id: u64,
     pub name: String,
     pub room_count: u16,
     pub is_verified: bool,
+    /// Index of the hotel's `HotelRegistryEntry`
+    pub registry_index: u64,
+    pub pending_owner: Option<Pubkey>,
+    pub listing_count: u32,
+    pub room_type_count: u16,
+    pub active_bookings: u32,
+    /// Whether bookings can be resold through `buy_resale_booking`. Receipts otherwise stay
+    /// frozen in the renter's account.
+    pub transferable_receipts: bool,
+    /// Share of every booking resale paid to the hotel
+    pub resale_royalty_bps: u16,
+    /// Whether the hotel has issued fractional shares, which then take part in rent payouts
+    pub has_shares: bool,
+    /// Number of reviews left by guests with completed stays
+    pub rating_count: u64,
+    /// Sum of all review ratings; the average is `rating_sum / rating_count`
+    pub rating_sum: u64,
+    /// Highest room number created; `room_count` can't drop below it
+    pub max_room_number: u16,
+    /// Whether the hotel has been appraised, which then requires its `HotelCollateral` on close
+    pub has_collateral: bool,
+    /// Rooms and room types not yet closed. The hotel can only be closed without any, so a
+    /// hotel recreated at the same address starts empty.
+    pub open_rooms: u16,
+    pub open_room_types: u16,
+    pub bump: u8,
+}
+
+impl Hotel {
+    pub const LEN: usize = 32 + 32 + 8 + (4 + MAX_HOTEL_NAME_LEN) + 2 + 1 + 8 + (1 + 32) + 4 + 2 + 4 + 1 + 2 + 1 + 8 + 8 + 2 + 1 + 2 + 2 + 1;
+}
+
+/// Settings shared by a hotel's rooms of one kind. Nightly prices are set per `RentalListing`.
+#[account]
+pub struct RoomType {
+    pub hotel: Pubkey,
+    pub type_id: u16,
+    pub name: String,
+    pub capacity: u16,
+    /// Bitmask of amenities offered by rooms of this type
+    pub amenities: u32,
+    pub cancellation_policy: CancellationPolicy,
+    /// Refundable deposit collected with the rent, in the listing's payment mint
+    pub security_deposit: u64,
+    /// Days after check-out during which the hotel can claim against the deposit
+    pub damage_claim_window_days: u16,
+    /// Rooms of this type not yet closed
+    pub open_rooms: u16,
+    pub bump: u8,
+}
+
+impl RoomType {
+    pub const LEN: usize = 32 + 2 + (4 + MAX_ROOM_TYPE_NAME_LEN) + 2 + 4 + CancellationPolicy::LEN + 8 + 2 + 2 + 1;
+}
+
+/// Settings of a new room type, passed to `create_room_type`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RoomTypeParams {
+    pub name: String,
+    pub capacity: u16,
+    pub amenities: u32,
+    pub cancellation_policy: CancellationPolicy,
+    pub security_deposit: u64,
+    pub damage_claim_window_days: u16,
+}
+
+/// Changes passed to `update_room_type`; `None` leaves a setting unchanged.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RoomTypeUpdate {
+    pub name: Option<String>,
+    pub capacity: Option<u16>,
+    pub amenities: Option<u32>,
+    pub cancellation_policy: Option<CancellationPolicy>,
+    pub security_deposit: Option<u64>,
+    pub damage_claim_window_days: Option<u16>,
+}
+
+/// Refund rules applied when a renter cancels before check-in.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CancellationPolicy {
+    /// Cancelling at least this many days before check-in refunds the full amount
+    pub full_refund_days: u16,
+    /// Cancelling at least this many days before check-in refunds `partial_refund_bps`
+    pub partial_refund_days: u16,
+    pub partial_refund_bps: u16,
+    pub non_refundable: bool,
+}
+
+impl CancellationPolicy {
+    pub const LEN: usize = 2 + 2 + 2 + 1;
+
+    pub fn is_valid(&self) -> bool {
+        self.partial_refund_days <= self.full_refund_days && self.partial_refund_bps as u64 <= BASIS_POINTS
+    }
+
+    /// Portion of `amount` refunded when cancelling `days_before_check_in` full days ahead.
+    pub fn refund_amount(&self, amount: u64, days_before_check_in: u64) -> Option<u64> {
+        if self.non_refundable {
+            Some(0)
+        } else if days_before_check_in >= self.full_refund_days as u64 {
+            Some(amount)
+        } else if days_before_check_in >= self.partial_refund_days as u64 {
+            let refund = amount as u128 * self.partial_refund_bps as u128 / BASIS_POINTS as u128;
+            u64::try_from(refund).ok()
+        } else {
+            Some(0)
+        }
+    }
+}
+
+#[account]
+pub struct Room {
+    pub hotel: Pubkey,
+    pub room_number: u16,
+    pub room_type: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+pub struct HotelRegistry {
+    pub hotel_count: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct HotelRegistryEntry {
+    pub hotel: Pubkey,
+    pub bump: u8,
+}
+
+/// Availability of a room over `CALENDAR_WINDOW_DAYS` consecutive nights, where
+/// bit `n` marks night `window * CALENDAR_WINDOW_DAYS + n` (days since the Unix epoch) as booked.
+#[account]
+pub struct RoomCalendar {
+    pub room: Pubkey,
+    pub window: u32,
+    pub booked_nights: u32,
+    pub bump: u8,
+}
+
+impl RoomCalendar {
+    pub fn is_booked(&self, day: u32) -> bool {
+        self.booked_nights & Self::night_mask(day) != 0
+    }
+
+    pub fn reserve(&mut self, day: u32) {
+        self.booked_nights |= Self::night_mask(day);
+    }
+
+    pub fn release(&mut self, day: u32) {
+        self.booked_nights &= !Self::night_mask(day);
+    }
+
+    /// Whether a stay of `nights` from `check_in_day` runs into the following window.
+    pub fn spans_next_window(check_in_day: u32, nights: u16) -> bool {
+        (check_in_day + nights as u32 - 1) / CALENDAR_WINDOW_DAYS != check_in_day / CALENDAR_WINDOW_DAYS
+    }
+
+    fn night_mask(day: u32) -> u32 {
+        1 << (day % CALENDAR_WINDOW_DAYS)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum BookingStatus {
+    Reserved,
+    CheckedIn,
+    CheckedOut,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DepositStatus {
+    Held,
+    Claimed,
+    Released,
+}
+
+#[account]
+pub struct Booking {
+    pub hotel: Pubkey,
+    pub room: Pubkey,
+    pub renter: Pubkey,
+    /// First night of the stay, in days since the Unix epoch
+    pub check_in_day: u32,
+    pub nights: u16,
+    pub payment_mint: Pubkey,
+    pub amount: u64,
+    /// Snapshot of the room type's policy at booking time
+    pub cancellation_policy: CancellationPolicy,
+    pub status: BookingStatus,
+    pub created_at: i64,
+    /// Whether the receipt was minted transferable, which allows the booking to be resold
+    pub transferable_receipt: bool,
+    /// Deposit held in escrow alongside the rent, in `payment_mint` units
+    pub security_deposit: u64,
+    pub damage_claim_window_days: u16,
+    pub deposit_status: DepositStatus,
+    /// Amount of the deposit claimed by the hotel for damages
+    pub damage_claim: u64,
+    pub checked_out_at: i64,
+    /// Snapshot of the protocol fee at booking time, charged on rent paid out to the hotel
+    pub protocol_fee_bps: u16,
+    /// Snapshot of the staker revenue share at booking time; zero unless paid in the reward mint
+    pub revenue_share_bps: u16,
+    pub bump: u8,
+}
+
+impl Booking {
+    pub const LEN: usize = 32 + 32 + 32 + 4 + 2 + 32 + 8 + CancellationPolicy::LEN + 1 + 8 + 1 + 8 + 2 + 1 + 8 + 8 + 2 + 2 + 1;
+
+    pub fn check_in_timestamp(&self) -> i64 {
+        self.check_in_day as i64 * SECONDS_PER_DAY
+    }
+
+    pub fn check_out_timestamp(&self) -> i64 {
+        (self.check_in_day as i64 + self.nights as i64) * SECONDS_PER_DAY
+    }
+
+    /// Last moment the hotel can file a damage claim; only meaningful once checked out. The
+    /// window runs from the end of the stay even if the renter checked out early, since they
+    /// keep the room until then.
+    pub fn damage_claim_deadline(&self) -> i64 {
+        self.checked_out_at.max(self.check_out_timestamp()) + self.damage_claim_window_days as i64 * SECONDS_PER_DAY
+    }
 }
 
+/// A guest review of a completed stay; the review text lives off-chain under `content_hash`.
+#[account]
+pub struct Review {
+    pub booking: Pubkey,
+    pub hotel: Pubkey,
+    pub reviewer: Pubkey,
+    pub rating: u8,
+    pub content_hash: [u8; 32],
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+/// A booking offered for sale by its renter at a fixed price in the booking's payment mint.
+#[account]
+pub struct ResaleListing {
+    pub booking: Pubkey,
+    pub seller: Pubkey,
+    pub price: u64,
+    pub bump: u8,
+}
+
+/// Constant-product COCO/USDC pool holding its reserves in vaults at
+/// `[b"pool_vault", pool, mint]`. Bookings are priced from its time-weighted average price.
 #[account]
 pub struct LiquidityPool {
     pub total_liquidity: u64,
     pub coco_token_reserve: u64,
     pub usdc_reserve: u64,
+    pub coco_mint: Pubkey,
+    pub usdc_mint: Pubkey,
+    /// Time integral of the spot COCO price in USDC, scaled by `PRICE_PRECISION`. Wraps on
+    /// overflow; only differences are meaningful.
+    pub price_cumulative: u128,
+    pub last_update_timestamp: i64,
+    /// Start of the span the TWAP is measured over
+    pub previous_observation: PriceObservation,
+    /// Becomes `previous_observation` once it is `twap_window` old
+    pub latest_observation: PriceObservation,
+    /// Shortest span the TWAP averages over; quotes fail once it spans more than twice this
+    pub twap_window: i64,
+    /// May change the pool's parameters
+    pub authority: Pubkey,
+    /// Proposed new authority; takes over once it accepts
+    pub pending_authority: Option<Pubkey>,
+    pub bump: u8,
+}
+
+impl LiquidityPool {
+    pub const LEN: usize = 8 + 8 + 8 + 32 + 32 + 16 + 8 + PriceObservation::LEN * 2 + 8 + 32 + (1 + 32) + 1;
+
+    /// Spot COCO price in USDC, scaled by `PRICE_PRECISION`.
+    pub fn spot_price(&self) -> Option<u128> {
+        if self.coco_token_reserve == 0 {
+            return None;
+        }
+
+        Some(self.usdc_reserve as u128 * PRICE_PRECISION / self.coco_token_reserve as u128)
+    }
+
+    /// Accrues the spot price up to `now` and rolls the observations forward. Call before
+    /// changing the reserves, so a price only counts for as long as it was held.
+    pub fn update_price(&mut self, now: i64) {
+        let elapsed = now - self.last_update_timestamp;
+        if elapsed > 0 {
+            let price = self.spot_price().unwrap_or(0);
+            self.price_cumulative = self.price_cumulative.wrapping_add(price.wrapping_mul(elapsed as u128));
+            self.last_update_timestamp = now;
+        }
+
+        if now - self.latest_observation.timestamp >= self.twap_window {
+            self.previous_observation = self.latest_observation;
+            self.latest_observation = PriceObservation {
+                price_cumulative: self.price_cumulative,
+                timestamp: now,
+            };
+        }
+    }
+
+    /// Average COCO price in USDC since `previous_observation`, scaled by `PRICE_PRECISION`.
+    /// `None` if that span is shorter than the window, stale, or the pool was empty.
+    pub fn twap_price(&self, now: i64) -> Option<u128> {
+        let span = now - self.previous_observation.timestamp;
+        if span < self.twap_window || span > self.twap_window * 2 {
+            return None;
+        }
+
+        let pending = self
+            .spot_price()
+            .unwrap_or(0)
+            .wrapping_mul((now - self.last_update_timestamp) as u128);
+        let cumulative = self.price_cumulative.wrapping_add(pending);
+        let price = cumulative.wrapping_sub(self.previous_observation.price_cumulative) / span as u128;
+        (price > 0).then_some(price)
+    }
+
+    /// COCO needed to cover `usdc_amount` at the TWAP, rounded up.
+    pub fn usdc_to_coco(&self, usdc_amount: u64, now: i64) -> Option<u64> {
+        let price = self.twap_price(now)?;
+        let numerator = (usdc_amount as u128).checked_mul(PRICE_PRECISION)?;
+        u64::try_from(numerator.div_ceil(price)).ok()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct PriceObservation {
+    pub price_cumulative: u128,
+    pub timestamp: i64,
+}
+
+impl PriceObservation {
+    pub const LEN: usize = 16 + 8;
 }
 
 #[account]
 pub struct StakeAccount {
     pub owner: Pubkey,
     pub staked_amount: u64,
+    /// Last stake or unstake; the stake only counts toward discounts `MIN_DISCOUNT_STAKE_AGE` after
     pub last_stake_timestamp: i64,
+    /// Revenue already accounted for at the current stake, scaled by `REWARD_PRECISION`
+    pub reward_debt: u128,
+    /// Revenue earned but not yet claimed, in reward mint units
+    pub pending_rewards: u64,
+    pub bump: u8,
+}
+
+impl StakeAccount {
+    pub const LEN: usize = 32 + 8 + 8 + 16 + 8 + 1;
+}
+
+/// Reward-per-share accumulator paying out rewards pro-rata to deposited amounts, so
+/// distribution costs the same regardless of the number of participants.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct RewardAccumulator {
+    pub total_deposited: u64,
+    /// Rewards per deposited base unit, scaled by `REWARD_PRECISION`
+    pub reward_per_share: u128,
+    /// Rewards received while nothing was deposited, paid out with the next distribution
+    pub undistributed: u64,
+    pub total_distributed: u64,
+}
+
+impl RewardAccumulator {
+    pub const LEN: usize = 8 + 16 + 8 + 8;
+
+    pub fn distribute(&mut self, amount: u64) -> Option<()> {
+        if self.total_deposited == 0 {
+            self.undistributed = self.undistributed.checked_add(amount)?;
+            return Some(());
+        }
+
+        let total = amount.checked_add(self.undistributed)?;
+        let increment = (total as u128).checked_mul(REWARD_PRECISION)? / self.total_deposited as u128;
+        self.reward_per_share = self.reward_per_share.checked_add(increment)?;
+        self.total_distributed = self.total_distributed.checked_add(total)?;
+        self.undistributed = 0;
+        Some(())
+    }
+
+    /// Rewards accrued by `amount` since the accumulator started, scaled by `REWARD_PRECISION`.
+    pub fn accrued(&self, amount: u64) -> Option<u128> {
+        (amount as u128).checked_mul(self.reward_per_share)
+    }
+
+    /// Moves rewards accrued by a position of `amount` since its last update into `pending`.
+    /// Call before changing the position, then set its reward debt to `accrued` of the new amount.
+    pub fn settle(&self, amount: u64, reward_debt: u128, pending: &mut u64) -> Option<()> {
+        let earned = u64::try_from(self.accrued(amount)?.checked_sub(reward_debt)? / REWARD_PRECISION).ok()?;
+        *pending = pending.checked_add(earned)?;
+        Some(())
+    }
+}
+
+/// Pays out a share of booking revenue to COCO stakers pro-rata to their stake.
+#[account]
+pub struct RevenueDistributor {
+    /// Mint stakers lock up (COCO)
+    pub stake_mint: Pubkey,
+    /// Mint revenue is paid in (USDC); bookings paid in other mints don't contribute
+    pub reward_mint: Pubkey,
+    pub rewards: RewardAccumulator,
+    pub bump: u8,
+}
+
+impl RevenueDistributor {
+    pub const LEN: usize = 32 + 32 + RewardAccumulator::LEN + 1;
+}
+
+/// Fractional ownership of a single hotel. Shareholders deposit their shares to earn a fixed
+/// portion of the hotel's rental income, paid in `income_mint`.
+#[account]
+pub struct HotelShares {
+    pub hotel: Pubkey,
+    pub share_mint: Pubkey,
+    pub income_mint: Pubkey,
+    pub max_supply: u64,
+    pub issued_supply: u64,
+    /// Share of the hotel's rent payouts distributed to shareholders; fixed at creation
+    pub income_share_bps: u16,
+    pub rewards: RewardAccumulator,
+    pub bump: u8,
+}
+
+impl HotelShares {
+    pub const LEN: usize = 32 + 32 + 32 + 8 + 8 + 2 + RewardAccumulator::LEN + 1;
+}
+
+/// Shares of one hotel deposited by an investor to earn its rental income.
+#[account]
+pub struct HotelSharePosition {
+    pub owner: Pubkey,
+    pub hotel_shares: Pubkey,
+    pub amount: u64,
+    pub reward_debt: u128,
+    pub pending_rewards: u64,
+    pub bump: u8,
+}
+
+impl HotelSharePosition {
+    pub const LEN: usize = 32 + 32 + 8 + 16 + 8 + 1;
 }
 
 #[account]
 pub struct RentalListing {
     pub owner: Pubkey,
+    pub hotel: Pubkey,
+    pub room: Pubkey,
     pub room_number: u16,
+    /// Mint renters must pay in
+    pub mint: Pubkey,
+    /// Nightly price in `mint` base units
     pub price: u64,
     pub is_active: bool,
+    /// Whether a `PricingRules` account must be used when quoting this listing
+    pub has_pricing_rules: bool,
+    pub bump: u8,
+}
+
+impl RentalListing {
+    /// Total price of a stay, applying the listing's pricing rules when present.
+    pub fn quote(&self, pricing_rules: Option<&PricingRules>, check_in_day: u32, nights: u16) -> Option<u64> {
+        match pricing_rules {
+            Some(rules) => rules.quote(self.price, check_in_day, nights),
+            None => self.price.checked_mul(nights as u64),
+        }
+    }
+}
+
+/// Nightly price override for the nights in `[start_day, end_day)`, in days since the Unix epoch.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct SeasonalRate {
+    pub start_day: u32,
+    pub end_day: u32,
+    pub price: u64,
+}
+
+/// Discount applied to the whole stay when it lasts at least `min_nights`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct StayDiscount {
+    pub min_nights: u16,
+    pub discount_bps: u16,
+}
+
+#[account]
+pub struct PricingRules {
+    pub rental_listing: Pubkey,
+    /// Checked in order; the first rate covering a night wins
+    pub seasonal_rates: Vec<SeasonalRate>,
+    /// Surcharge on Friday and Saturday nights
+    pub weekend_surcharge_bps: u16,
+    pub stay_discounts: Vec<StayDiscount>,
+    pub bump: u8,
+}
+
+impl PricingRules {
+    pub const LEN: usize = 32 + (4 + 16 * MAX_SEASONAL_RATES) + 2 + (4 + 4 * MAX_STAY_DISCOUNTS) + 1;
+
+    pub fn is_valid(&self) -> bool {
+        self.seasonal_rates.len() <= MAX_SEASONAL_RATES
+            && self.stay_discounts.len() <= MAX_STAY_DISCOUNTS
+            && self.weekend_surcharge_bps as u64 <= BASIS_POINTS
+            && self.seasonal_rates.iter().all(|rate| rate.start_day < rate.end_day)
+            && self
+                .stay_discounts
+                .iter()
+                .all(|discount| discount.min_nights > 0 && discount.discount_bps as u64 <= BASIS_POINTS)
+    }
+
+    /// Sums the nightly prices of the stay, then applies the best length-of-stay discount.
+    pub fn quote(&self, base_price: u64, check_in_day: u32, nights: u16) -> Option<u64> {
+        let mut total: u128 = 0;
+
+        for day in check_in_day..check_in_day.checked_add(nights as u32)? {
+            let nightly = self
+                .seasonal_rates
+                .iter()
+                .find(|rate| rate.start_day <= day && day < rate.end_day)
+                .map_or(base_price, |rate| rate.price) as u128;
+
+            let surcharge = if is_weekend_night(day) {
+                nightly * self.weekend_surcharge_bps as u128 / BASIS_POINTS as u128
+            } else {
+                0
+            };
+
+            total = total.checked_add(nightly + surcharge)?;
+        }
+
+        let discount_bps = self
+            .stay_discounts
+            .iter()
+            .filter(|discount| nights >= discount.min_nights)
+            .map(|discount| discount.discount_bps)
+            .max()
+            .unwrap_or(0);
+        let discount = total * discount_bps as u128 / BASIS_POINTS as u128;
+
+        u64::try_from(total - discount).ok()
+    }
+}
+
+/// Friday and Saturday nights; day 0 (1970-01-01) was a Thursday.
+fn is_weekend_night(day: u32) -> bool {
+    matches!((day + 4) % 7, 5 | 6)
 }
 
 #[account]
 pub struct CocoMint {
     pub authority: Pubkey,
+    /// Proposed new authority; takes over once it accepts
+    pub pending_authority: Option<Pubkey>,
+    /// Token-2022 mint whose mint authority is this account's PDA
+    pub mint: Pubkey,
     pub total_supply: u64,
+    /// Hard cap on `total_supply`; fixed at initialization
+    pub max_supply: u64,
+    /// Length of an emission epoch in seconds; fixed at initialization
+    pub epoch_duration: i64,
+    /// Most COCO that can be issued in a single epoch; fixed at initialization
+    pub epoch_allowance: u64,
+    pub current_epoch_start: i64,
+    pub minted_this_epoch: u64,
+    pub bump: u8,
+}
+
+impl CocoMint {
+    pub const LEN: usize = 32 + (1 + 32) + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 1;
+
+    /// Rolls over to the epoch containing `now`, resetting the epoch's issuance.
+    pub fn advance_epoch(&mut self, now: i64) {
+        let elapsed = now - self.current_epoch_start;
+        if elapsed >= self.epoch_duration {
+            self.current_epoch_start += elapsed / self.epoch_duration * self.epoch_duration;
+            self.minted_this_epoch = 0;
+        }
+    }
+
+    /// Records `amount` as issued at `now`, keeping within the supply cap and the epoch's allowance.
+    pub fn record_issuance(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.advance_epoch(now);
+
+        let total_supply = self.total_supply.checked_add(amount).ok_or(ErrorCode::Overflow)?;
+        require!(total_supply <= self.max_supply, ErrorCode::MaxSupplyExceeded);
+        let minted_this_epoch = self.minted_this_epoch.checked_add(amount).ok_or(ErrorCode::Overflow)?;
+        require!(minted_this_epoch <= self.epoch_allowance, ErrorCode::EpochAllowanceExceeded);
+
+        self.total_supply = total_supply;
+        self.minted_this_epoch = minted_this_epoch;
+        Ok(())
+    }
+}
+
+/// Verifier-attested appraisal of a hotel backing the COCO issued against it.
+#[account]
+pub struct HotelCollateral {
+    pub hotel: Pubkey,
+    /// Appraised value of the property, in COCO base units
+    pub appraised_value: u64,
+    pub appraiser: Pubkey,
+    pub appraised_at: i64,
+    /// COCO currently outstanding against this hotel
+    pub issued_amount: u64,
+    pub bump: u8,
+}
+
+impl HotelCollateral {
+    pub const LEN: usize = 32 + 8 + 32 + 8 + 8 + 1;
+
+    /// Most COCO that can be outstanding against this hotel at `max_ltv_bps`.
+    pub fn issuance_limit(&self, max_ltv_bps: u16) -> Option<u64> {
+        bps_of(self.appraised_value, max_ltv_bps)
+    }
+}
+
+/// COCO locked for a beneficiary and released linearly over `duration` seconds from `start`,
+/// with nothing claimable before the cliff.
+#[account]
+pub struct Vesting {
+    /// Funded the grant, either from its own COCO or by issuing into the vault
+    pub creator: Pubkey,
+    pub beneficiary: Pubkey,
+    pub vesting_id: u64,
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub start: i64,
+    /// Seconds after `start` before anything unlocks
+    pub cliff: i64,
+    /// Seconds after `start` until everything has unlocked
+    pub duration: i64,
+    /// Whether the protocol admin may stop the schedule, returning the unvested COCO to `creator`
+    pub revocable: bool,
+    /// Vesting stops accruing at this time once revoked
+    pub revoked_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl Vesting {
+    pub const LEN: usize = 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + (1 + 8) + 1;
+
+    /// Total amount unlocked at `now`, including anything already claimed.
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        let now = self.revoked_at.map_or(now, |revoked_at| now.min(revoked_at));
+        let elapsed = now - self.start;
+
+        if elapsed < self.cliff {
+            0
+        } else if elapsed >= self.duration {
+            self.total_amount
+        } else {
+            (self.total_amount as u128 * elapsed as u128 / self.duration as u128) as u64
+        }
+    }
+}
+
+/// Schedule of a new grant, passed to `create_vesting` and `issue_vested_coco_tokens`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VestingParams {
+    pub beneficiary: Pubkey,
+    pub vesting_id: u64,
+    pub total_amount: u64,
+    pub start: i64,
+    pub cliff: i64,
+    pub duration: i64,
+    pub revocable: bool,
+}
+
+/// M-of-N set of keys acting through its signer PDA at `[b"multisig_signer", multisig]`,
+/// which can be made the authority of the COCO mint, the protocol config or a pool.
+#[account]
+pub struct Multisig {
+    pub create_key: Pubkey,
+    pub members: Vec<Pubkey>,
+    /// Approvals needed to execute a proposal
+    pub threshold: u8,
+    /// Bumped whenever the members change so open proposals can't be executed under the new set
+    pub member_set_seqno: u32,
+    pub proposal_count: u64,
+    pub bump: u8,
+    pub signer_bump: u8,
+}
+
+impl Multisig {
+    pub const LEN: usize = 32 + (4 + 32 * MAX_MULTISIG_MEMBERS) + 1 + 4 + 8 + 1 + 1;
+
+    pub fn member_index(&self, key: &Pubkey) -> Option<usize> {
+        self.members.iter().position(|member| member == key)
+    }
+}
+
+/// An instruction the multisig signer will invoke once enough members approve it.
+#[account]
+pub struct MultisigProposal {
+    pub multisig: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub program_id: Pubkey,
+    pub accounts: Vec<ProposalAccountMeta>,
+    pub data: Vec<u8>,
+    /// Approval by each member, in the order of `Multisig::members`
+    pub approvals: Vec<bool>,
+    pub member_set_seqno: u32,
+    pub executed: bool,
+    pub bump: u8,
+}
+
+impl MultisigProposal {
+    pub const LEN: usize = 32
+        + 8
+        + 32
+        + 32
+        + (4 + ProposalAccountMeta::LEN * MAX_PROPOSAL_ACCOUNTS)
+        + (4 + MAX_PROPOSAL_DATA_LEN)
+        + (4 + MAX_MULTISIG_MEMBERS)
+        + 4
+        + 1
+        + 1;
+
+    pub fn approval_count(&self) -> usize {
+        self.approvals.iter().filter(|approved| **approved).count()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ProposalAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl ProposalAccountMeta {
+    pub const LEN: usize = 32 + 1 + 1;
+}
+
+#[account]
+pub struct ProtocolConfig {
+    pub admin: Pubkey,
+    /// Proposed new admin; takes over once it accepts
+    pub pending_admin: Option<Pubkey>,
+    pub verifiers: Vec<Pubkey>,
+    /// Share of rent released to hotels that is paid into the treasury
+    pub protocol_fee_bps: u16,
+    /// Share of rent released to hotels that is distributed to COCO stakers
+    pub revenue_share_bps: u16,
+    /// Most COCO that can be outstanding against a hotel, as a share of its appraised value
+    pub max_ltv_bps: u16,
+    pub bump: u8,
+}
+
+impl ProtocolConfig {
+    pub const LEN: usize = 32 + (1 + 32) + (4 + 32 * MAX_VERIFIERS) + 2 + 2 + 2 + 1;
+}
+
+/// `bps` basis points of `amount`, rounded down.
+pub fn bps_of(amount: u64, bps: u16) -> Option<u64> {
+    u64::try_from(amount as u128 * bps as u128 / BASIS_POINTS as u128).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn moderate_policy() -> CancellationPolicy {
+        CancellationPolicy {
+            full_refund_days: 7,
+            partial_refund_days: 2,
+            partial_refund_bps: 5_000,
+            non_refundable: false,
+        }
+    }
+
+    #[test]
+    fn refund_is_full_from_the_full_refund_day() {
+        let policy = moderate_policy();
+        assert_eq!(policy.refund_amount(1_000, 7), Some(1_000));
+        assert_eq!(policy.refund_amount(1_000, 30), Some(1_000));
+    }
+
+    #[test]
+    fn refund_is_partial_between_the_tiers() {
+        let policy = moderate_policy();
+        assert_eq!(policy.refund_amount(1_000, 6), Some(500));
+        assert_eq!(policy.refund_amount(1_000, 2), Some(500));
+        // Rounded down
+        assert_eq!(policy.refund_amount(999, 2), Some(499));
+    }
+
+    #[test]
+    fn refund_is_nothing_after_the_partial_refund_day() {
+        let policy = moderate_policy();
+        assert_eq!(policy.refund_amount(1_000, 1), Some(0));
+        assert_eq!(policy.refund_amount(1_000, 0), Some(0));
+    }
+
+    #[test]
+    fn non_refundable_policy_never_refunds() {
+        let policy = CancellationPolicy { non_refundable: true, ..moderate_policy() };
+        assert_eq!(policy.refund_amount(1_000, 30), Some(0));
+        assert_eq!(policy.refund_amount(1_000, 2), Some(0));
+    }
+
+    #[derive(Default)]
+    struct Position {
+        amount: u64,
+        reward_debt: u128,
+        pending: u64,
+    }
+
+    fn deposit(rewards: &mut RewardAccumulator, position: &mut Position, amount: u64) {
+        rewards.settle(position.amount, position.reward_debt, &mut position.pending).unwrap();
+        position.amount += amount;
+        position.reward_debt = rewards.accrued(position.amount).unwrap();
+        rewards.total_deposited += amount;
+    }
+
+    fn withdraw(rewards: &mut RewardAccumulator, position: &mut Position, amount: u64) {
+        rewards.settle(position.amount, position.reward_debt, &mut position.pending).unwrap();
+        position.amount -= amount;
+        position.reward_debt = rewards.accrued(position.amount).unwrap();
+        rewards.total_deposited -= amount;
+    }
+
+    fn claimable(rewards: &RewardAccumulator, position: &Position) -> u64 {
+        let mut pending = position.pending;
+        rewards.settle(position.amount, position.reward_debt, &mut pending).unwrap();
+        pending
+    }
+
+    #[test]
+    fn rewards_without_deposits_go_to_the_next_distribution() {
+        let mut rewards = RewardAccumulator::default();
+        rewards.distribute(100).unwrap();
+        assert_eq!(rewards.reward_per_share, 0);
+        assert_eq!(rewards.undistributed, 100);
+        assert_eq!(rewards.total_distributed, 0);
+
+        let mut alice = Position::default();
+        deposit(&mut rewards, &mut alice, 50);
+        assert_eq!(claimable(&rewards, &alice), 0);
+
+        rewards.distribute(50).unwrap();
+        assert_eq!(rewards.undistributed, 0);
+        assert_eq!(rewards.total_distributed, 150);
+        assert_eq!(claimable(&rewards, &alice), 150);
+    }
+
+    #[test]
+    fn rewards_round_down_and_never_exceed_the_distribution() {
+        let mut rewards = RewardAccumulator::default();
+        let (mut alice, mut bob) = (Position::default(), Position::default());
+        deposit(&mut rewards, &mut alice, 1);
+        deposit(&mut rewards, &mut bob, 2);
+
+        rewards.distribute(100).unwrap();
+        assert_eq!(claimable(&rewards, &alice), 33);
+        assert_eq!(claimable(&rewards, &bob), 66);
+    }
+
+    #[test]
+    fn withdrawal_between_distributions_keeps_earned_rewards() {
+        let mut rewards = RewardAccumulator::default();
+        let (mut alice, mut bob) = (Position::default(), Position::default());
+        deposit(&mut rewards, &mut alice, 100);
+        deposit(&mut rewards, &mut bob, 100);
+
+        rewards.distribute(200).unwrap();
+        withdraw(&mut rewards, &mut alice, 50);
+        assert_eq!(alice.pending, 100);
+
+        rewards.distribute(150).unwrap();
+        assert_eq!(claimable(&rewards, &alice), 150);
+        assert_eq!(claimable(&rewards, &bob), 200);
+        assert_eq!(rewards.total_distributed, 350);
+    }
+
+    #[test]
+    fn deposit_after_a_distribution_earns_nothing_from_it() {
+        let mut rewards = RewardAccumulator::default();
+        let (mut alice, mut bob) = (Position::default(), Position::default());
+        deposit(&mut rewards, &mut alice, 100);
+        rewards.distribute(100).unwrap();
+        deposit(&mut rewards, &mut bob, 100);
+
+        assert_eq!(claimable(&rewards, &alice), 100);
+        assert_eq!(claimable(&rewards, &bob), 0);
+    }
+
+    fn pricing_rules() -> PricingRules {
+        PricingRules {
+            rental_listing: Pubkey::default(),
+            seasonal_rates: vec![SeasonalRate { start_day: 7, end_day: 9, price: 200 }],
+            weekend_surcharge_bps: 5_000,
+            stay_discounts: vec![
+                StayDiscount { min_nights: 7, discount_bps: 500 },
+                StayDiscount { min_nights: 14, discount_bps: 1_000 },
+            ],
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn weekend_nights_are_friday_and_saturday() {
+        // Day 0 (1970-01-01) was a Thursday
+        let weekend: Vec<bool> = (0..10).map(is_weekend_night).collect();
+        assert_eq!(
+            weekend,
+            [false, true, true, false, false, false, false, false, true, true]
+        );
+        // 2024-01-05 was a Friday
+        assert!(is_weekend_night(19_727));
+        assert!(is_weekend_night(19_728));
+        assert!(!is_weekend_night(19_729));
+    }
+
+    #[test]
+    fn quote_applies_weekend_surcharge_outside_the_season() {
+        let rules = pricing_rules();
+        // Thursday to Sunday: Friday and Saturday nights carry the surcharge
+        assert_eq!(rules.quote(100, 0, 3), Some(100 + 150 + 150));
+    }
+
+    #[test]
+    fn quote_combines_season_and_weekend_across_a_week_boundary() {
+        let rules = pricing_rules();
+        // Days 0..10: weekend nights 1, 2, 8 and 9; season covers days 7 and 8,
+        // so day 8 is a seasonal weekend night
+        let total = 100 + 150 + 150 + 100 * 4 + 200 + 300 + 150;
+        // The 7-night discount applies, rounded down
+        assert_eq!(rules.quote(100, 0, 10), Some(total - total * 500 / 10_000));
+        assert_eq!(rules.quote(100, 0, 10), Some(1_378));
+    }
+
+    #[test]
+    fn quote_uses_the_best_stay_discount() {
+        let rules = pricing_rules();
+        let undiscounted = PricingRules { stay_discounts: vec![], ..pricing_rules() }
+            .quote(100, 3, 14)
+            .unwrap();
+        assert_eq!(rules.quote(100, 3, 14), Some(undiscounted - undiscounted / 10));
+    }
+
+    fn coco_mint() -> CocoMint {
+        CocoMint {
+            authority: Pubkey::default(),
+            pending_authority: None,
+            mint: Pubkey::default(),
+            total_supply: 0,
+            max_supply: 1_000,
+            epoch_duration: 100,
+            epoch_allowance: 300,
+            current_epoch_start: 1_000,
+            minted_this_epoch: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn epoch_does_not_roll_over_before_its_end() {
+        let mut mint = CocoMint { minted_this_epoch: 200, ..coco_mint() };
+        mint.advance_epoch(1_099);
+        assert_eq!(mint.current_epoch_start, 1_000);
+        assert_eq!(mint.minted_this_epoch, 200);
+    }
+
+    #[test]
+    fn epoch_rolls_over_to_the_current_one_after_skipped_epochs() {
+        let mut mint = CocoMint { minted_this_epoch: 200, ..coco_mint() };
+        mint.advance_epoch(1_100);
+        assert_eq!(mint.current_epoch_start, 1_100);
+        assert_eq!(mint.minted_this_epoch, 0);
+
+        // Three epochs skipped; the start stays aligned to the schedule
+        mint.minted_this_epoch = 200;
+        mint.advance_epoch(1_450);
+        assert_eq!(mint.current_epoch_start, 1_400);
+        assert_eq!(mint.minted_this_epoch, 0);
+    }
+
+    #[test]
+    fn issuance_is_limited_by_the_epoch_allowance() {
+        let mut mint = coco_mint();
+        mint.record_issuance(300, 1_000).unwrap();
+        assert_eq!(
+            mint.record_issuance(1, 1_099).unwrap_err(),
+            ErrorCode::EpochAllowanceExceeded.into()
+        );
+
+        // The allowance resets with the next epoch
+        mint.record_issuance(300, 1_100).unwrap();
+        assert_eq!(mint.total_supply, 600);
+        assert_eq!(mint.minted_this_epoch, 300);
+    }
+
+    #[test]
+    fn issuance_is_limited_by_the_supply_cap() {
+        let mut mint = CocoMint { total_supply: 900, ..coco_mint() };
+        assert_eq!(
+            mint.record_issuance(101, 1_000).unwrap_err(),
+            ErrorCode::MaxSupplyExceeded.into()
+        );
+        assert_eq!(mint.total_supply, 900);
+        assert_eq!(mint.minted_this_epoch, 0);
+
+        mint.record_issuance(100, 1_000).unwrap();
+        assert_eq!(mint.total_supply, 1_000);
+    }
+
+    fn vesting() -> Vesting {
+        Vesting {
+            creator: Pubkey::default(),
+            beneficiary: Pubkey::default(),
+            vesting_id: 0,
+            total_amount: 1_000,
+            claimed_amount: 0,
+            start: 10_000,
+            cliff: 250,
+            duration: 1_000,
+            revocable: true,
+            revoked_at: None,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn nothing_vests_before_the_cliff() {
+        let vesting = vesting();
+        assert_eq!(vesting.vested_amount(9_000), 0);
+        assert_eq!(vesting.vested_amount(10_249), 0);
+    }
+
+    #[test]
+    fn cliff_releases_everything_accrued_so_far() {
+        assert_eq!(vesting().vested_amount(10_250), 250);
+    }
+
+    #[test]
+    fn vesting_is_linear_between_cliff_and_end() {
+        let vesting = vesting();
+        assert_eq!(vesting.vested_amount(10_500), 500);
+        // Rounded down
+        let vesting = Vesting { total_amount: 999, ..vesting };
+        assert_eq!(vesting.vested_amount(10_500), 499);
+    }
+
+    #[test]
+    fn everything_vests_after_the_end() {
+        let vesting = vesting();
+        assert_eq!(vesting.vested_amount(11_000), 1_000);
+        assert_eq!(vesting.vested_amount(20_000), 1_000);
+    }
+
+    #[test]
+    fn vesting_stops_at_revocation() {
+        let vesting = Vesting { revoked_at: Some(10_400), ..vesting() };
+        assert_eq!(vesting.vested_amount(10_400), 400);
+        assert_eq!(vesting.vested_amount(20_000), 400);
+
+        let revoked_before_cliff = Vesting { revoked_at: Some(10_100), ..vesting };
+        assert_eq!(revoked_before_cliff.vested_amount(20_000), 0);
+    }
 }
\ No newline at end of file